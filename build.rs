@@ -0,0 +1,283 @@
+//! Generates `src/dap/types.rs`'s contents from the vendored DAP schema.
+//!
+//! Messages themselves (`*Request`, `*Response`, `*Event`) aren't generated,
+//! `dap::requests` and `dap::messages` cover them. Their arguments and bodies
+//! are, bodies being named after the message, i.e. `StoppedEventBody`.
+//!
+//! Moving to a newer protocol version means replacing the schema file.
+
+use std::{collections::BTreeMap, env, fmt::Write, fs, path::Path};
+
+use serde_json::{Map, Value};
+
+const SCHEMA: &str = "schema/debugAdapterProtocol.json";
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", SCHEMA);
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let schema: Value = serde_json::from_str(&fs::read_to_string(SCHEMA).unwrap()).unwrap();
+    let definitions = schema["definitions"].as_object().unwrap();
+    let mut generator = Generator {
+        definitions,
+        items: BTreeMap::new(),
+    };
+    for (name, definition) in definitions {
+        generator.definition(name, definition);
+    }
+
+    let mut out = String::from("use serde::{Deserialize, Serialize};\n\n");
+    for item in generator.items.values() {
+        out.push_str(item);
+    }
+    let dest = Path::new(&env::var("OUT_DIR").unwrap()).join("dap_types.rs");
+    fs::write(dest, out).unwrap();
+}
+
+struct Generator<'a> {
+    definitions: &'a Map<String, Value>,
+    // Keyed by name so the output is sorted and stable
+    items: BTreeMap<String, String>,
+}
+
+// Properties and required fields, with those of `allOf` parts merged in
+#[derive(Default)]
+struct Object<'a> {
+    properties: BTreeMap<&'a str, &'a Value>,
+    required: Vec<&'a str>,
+}
+
+impl<'a> Generator<'a> {
+    fn definition(&mut self, name: &str, definition: &'a Value) {
+        // Generic envelopes live in `dap::messages`
+        if name == "Response" || name == "Event" {
+            return;
+        }
+        if let Some(base) = self.base(definition) {
+            if ["Request", "Response", "Event"].contains(&base) {
+                // Only the body is interesting, arguments are definitions
+                // of their own
+                let object = self.object(definition);
+                if let Some(body) = object.properties.get("body") {
+                    if body.get("properties").is_some() {
+                        self.structure(&format!("{}Body", name), body);
+                    }
+                }
+                return;
+            }
+        }
+        if let Some(values) = definition.get("enum") {
+            self.enumeration(name, definition, values);
+        } else if definition.get("_enum").is_some() {
+            // Open enumerations take any string
+            let mut item = doc(definition);
+            writeln!(item, "pub type {} = String;", name).unwrap();
+            self.items.insert(name.to_owned(), item);
+        } else {
+            self.structure(name, definition);
+        }
+    }
+
+    fn base(&self, definition: &Value) -> Option<&'a str> {
+        let reference = definition.get("allOf")?.get(0)?.get("$ref")?.as_str()?;
+        let name = reference.rsplit('/').next()?;
+        self.definitions
+            .get_key_value(name)
+            .map(|(k, _)| k.as_str())
+    }
+
+    fn object(&self, definition: &'a Value) -> Object<'a> {
+        let mut object = Object::default();
+        let parts: Vec<&Value> = match definition.get("allOf").and_then(Value::as_array) {
+            Some(parts) => parts.iter().collect(),
+            None => vec![definition],
+        };
+        for part in parts {
+            let part = match part.get("$ref").and_then(Value::as_str) {
+                Some(reference) => &self.definitions[reference.rsplit('/').next().unwrap()],
+                None => part,
+            };
+            if part.get("allOf").is_some() {
+                let inherited = self.object(part);
+                object.properties.extend(inherited.properties);
+                object.required.extend(inherited.required);
+            }
+            if let Some(properties) = part.get("properties").and_then(Value::as_object) {
+                for (name, property) in properties {
+                    object.properties.insert(name, property);
+                }
+            }
+            if let Some(required) = part.get("required").and_then(Value::as_array) {
+                object
+                    .required
+                    .extend(required.iter().filter_map(Value::as_str));
+            }
+        }
+        object
+    }
+
+    fn structure(&mut self, name: &str, definition: &'a Value) {
+        let object = self.object(definition);
+        let mut item = doc(definition);
+        if object.properties.is_empty() {
+            writeln!(
+                item,
+                "pub type {} = ::std::collections::BTreeMap<String, serde_json::Value>;",
+                name
+            )
+            .unwrap();
+            self.items.insert(name.to_owned(), item);
+            return;
+        }
+        let defaultable = object.required.is_empty();
+        writeln!(
+            item,
+            "#[derive(Clone, PartialEq, Debug, {}Deserialize, Serialize)]",
+            if defaultable { "Default, " } else { "" }
+        )
+        .unwrap();
+        writeln!(item, "pub struct {} {{", name).unwrap();
+        for (property, schema) in &object.properties {
+            let mut ty = self.type_of(&format!("{}{}", name, camel(property)), schema);
+            for line in doc(schema).lines() {
+                writeln!(item, "    {}", line).unwrap();
+            }
+            if !object.required.contains(property) {
+                ty = format!("Option<{}>", ty);
+                writeln!(
+                    item,
+                    "    #[serde(skip_serializing_if = \"Option::is_none\")]"
+                )
+                .unwrap();
+            }
+            let field = snake(property);
+            if field != *property {
+                writeln!(item, "    #[serde(rename = {:?})]", property).unwrap();
+            }
+            writeln!(item, "    pub {}: {},", field, ty).unwrap();
+        }
+        item.push_str("}\n");
+        self.items.insert(name.to_owned(), item);
+    }
+
+    fn enumeration(&mut self, name: &str, definition: &Value, values: &Value) {
+        let mut item = doc(definition);
+        item.push_str("#[derive(Clone, Copy, PartialEq, Eq, Debug, Deserialize, Serialize)]\n");
+        writeln!(item, "pub enum {} {{", name).unwrap();
+        for value in values.as_array().unwrap() {
+            let value = value.as_str().unwrap();
+            writeln!(item, "    #[serde(rename = {:?})]", value).unwrap();
+            writeln!(item, "    {},", variant(value)).unwrap();
+        }
+        item.push_str("}\n");
+        self.items.insert(name.to_owned(), item);
+    }
+
+    // `name` is what an inline object or union gets called
+    fn type_of(&mut self, name: &str, schema: &'a Value) -> String {
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            return reference.rsplit('/').next().unwrap().to_owned();
+        }
+        if let Some(variants) = schema.get("oneOf").and_then(Value::as_array) {
+            let mut item = String::from(
+                "#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]\n#[serde(untagged)]\n",
+            );
+            writeln!(item, "pub enum {} {{", name).unwrap();
+            for (i, variant) in variants.iter().enumerate() {
+                let ty = self.type_of(&format!("{}Variant{}", name, i), variant);
+                writeln!(item, "    Variant{}({}),", i, ty).unwrap();
+            }
+            item.push_str("}\n");
+            self.items.insert(name.to_owned(), item);
+            return name.to_owned();
+        }
+        match &schema["type"] {
+            Value::String(ty) => match ty.as_str() {
+                "array" => format!("Vec<{}>", self.type_of(name, &schema["items"])),
+                "object" if schema.get("properties").is_some() => {
+                    self.structure(name, schema);
+                    name.to_owned()
+                }
+                "object" => match schema.get("additionalProperties") {
+                    Some(values @ Value::Object(_)) => format!(
+                        "::std::collections::BTreeMap<String, {}>",
+                        self.type_of(name, values)
+                    ),
+                    _ => "::std::collections::BTreeMap<String, serde_json::Value>".to_owned(),
+                },
+                ty => primitive(ty).to_owned(),
+            },
+            // `["string", "null"]`, anything wider is left untyped
+            Value::Array(types) if types.len() == 2 && types.contains(&"null".into()) => {
+                let ty = types.iter().find(|t| *t != "null").unwrap();
+                format!("Option<{}>", primitive(ty.as_str().unwrap()))
+            }
+            _ => "serde_json::Value".to_owned(),
+        }
+    }
+}
+
+fn primitive(ty: &str) -> &'static str {
+    match ty {
+        "string" => "String",
+        "integer" => "i64",
+        "number" => "f64",
+        "boolean" => "bool",
+        _ => "serde_json::Value",
+    }
+}
+
+fn doc(schema: &Value) -> String {
+    let mut doc = String::new();
+    if let Some(description) = schema.get("description").and_then(Value::as_str) {
+        for line in description.lines() {
+            writeln!(doc, "#[doc = {:?}]", format!(" {}", line)).unwrap();
+        }
+    }
+    doc
+}
+
+// `linesStartAt1` becomes `lines_start_at_1`, `adapterID` `adapter_id`
+fn snake(name: &str) -> String {
+    if name == "type" {
+        return "type_".to_owned();
+    }
+    if let Some(name) = name.strip_prefix("__") {
+        return format!("_{}", snake(name));
+    }
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if i > 0 {
+            let prev = chars[i - 1];
+            let next_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            let boundary = (c.is_uppercase()
+                && (prev.is_lowercase()
+                    || prev.is_ascii_digit()
+                    || (prev.is_uppercase() && next_lower)))
+                || (c.is_ascii_digit() && prev.is_alphabetic());
+            if boundary {
+                snake.push('_');
+            }
+        }
+        snake.extend(c.to_lowercase());
+    }
+    snake
+}
+
+fn camel(name: &str) -> String {
+    let mut chars = name.trim_start_matches('_').chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+// `readWrite` becomes `ReadWrite`, `SHA256` `Sha256`
+fn variant(value: &str) -> String {
+    if value.chars().all(|c| !c.is_lowercase()) {
+        camel(&value.to_lowercase())
+    } else {
+        camel(value)
+    }
+}
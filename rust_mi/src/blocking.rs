@@ -0,0 +1,153 @@
+//! Blocking wrapper around [`MIController`], for callers without a tokio
+//! runtime of their own.
+
+use std::time::Duration;
+
+use tokio::{runtime::{Builder, Runtime},
+            sync::broadcast::{self, error::RecvError}};
+
+use crate::{commands::MiCommand, config::GdbConfig, controller::Event,
+            parser::output_types::OutputData, transport::MiTransport, types::Result, MIController};
+
+/// Runs an [`MIController`] on an internal runtime and blocks on each call.
+///
+/// Events are buffered from the moment the controller starts and handed out
+/// by [`next_event`](Controller::next_event).
+#[derive(Debug)]
+pub struct Controller {
+    // Dropped before the runtime, which has to outlive it
+    inner: Option<MIController>,
+    events: broadcast::Receiver<Event>,
+    runtime: Runtime,
+}
+
+impl Controller {
+    /// Starts `gdb` from `PATH` with the default [`GdbConfig`].
+    pub fn new() -> Result<Controller> {
+        Self::with_config(&GdbConfig::default())
+    }
+
+    pub fn with_config(config: &GdbConfig) -> Result<Controller> {
+        let runtime = runtime()?;
+        let inner = runtime.block_on(config.spawn())?;
+        Ok(Self::from_parts(runtime, inner))
+    }
+
+    pub fn with_transport<T: MiTransport + 'static>(transport: T) -> Result<Controller> {
+        let runtime = runtime()?;
+        let inner = {
+            let _context = runtime.enter();
+            MIController::with_transport(transport)?
+        };
+        Ok(Self::from_parts(runtime, inner))
+    }
+
+    fn from_parts(runtime: Runtime, inner: MIController) -> Controller {
+        Controller {
+            events: inner.events(),
+            inner: Some(inner),
+            runtime,
+        }
+    }
+
+    /// The wrapped controller, for the settings not exposed here.
+    pub fn inner(&self) -> &MIController {
+        self.inner.as_ref().expect("controller already gone")
+    }
+
+    /// See [`MIController::execute`].
+    pub fn execute(&self, command: MiCommand) -> Result<OutputData<'static>> {
+        self.runtime.block_on(self.inner().execute(command))
+    }
+
+    /// See [`MIController::cli`].
+    pub fn cli(&self, command: &str) -> Result<String> {
+        self.runtime.block_on(self.inner().cli(command))
+    }
+
+    /// Waits up to `timeout` for the next event, `None` if none came or GDB
+    /// is gone and every event was handed out.
+    ///
+    /// Events dropped because too many piled up are skipped.
+    pub fn next_event(&mut self, timeout: Duration) -> Option<Event> {
+        let events = &mut self.events;
+        self.runtime.block_on(async {
+            tokio::time::timeout(timeout, async {
+                loop {
+                    match events.recv().await {
+                        Ok(event) => return Some(event),
+                        Err(RecvError::Lagged(_)) => continue,
+                        Err(RecvError::Closed) => return None,
+                    }
+                }
+            })
+            .await
+            .ok()
+            .flatten()
+        })
+    }
+
+    /// Asks GDB to quit and waits for it.
+    pub fn exit(mut self) -> Result<()> {
+        match self.inner.take() {
+            Some(inner) => self.runtime.block_on(inner.exit()),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for Controller {
+    fn drop(&mut self) {
+        // Tasks and the child process are torn down from within the runtime
+        let _context = self.runtime.enter();
+        self.inner.take();
+    }
+}
+
+fn runtime() -> Result<Runtime> {
+    // One worker keeps reading GDB's output between calls
+    Ok(Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parser::output_types::{AsyncOutput, OutputClass, OOB},
+                testing::FakeGdb};
+
+    #[test]
+    fn test_blocking() {
+        // FakeGdb lives on its own runtime, like a real GDB would in its own
+        // process
+        let fake_runtime = runtime().unwrap();
+        let (fake, transport) = {
+            let _context = fake_runtime.enter();
+            FakeGdb::spawn()
+        };
+        fake.on("-break-insert", ["^done,bkpt={number=\"1\"}"])
+            .on("-interpreter-exec", ["~\"Num Type\\n\"", "^done"])
+            .on(
+                "-exec-run",
+                ["^running", "*stopped,reason=\"exited-normally\""],
+            );
+
+        let mut controller = Controller::with_transport(transport).unwrap();
+        let record = controller.execute(MiCommand::break_insert("main")).unwrap();
+        assert!(record.get("bkpt").is_some());
+        assert_eq!(controller.cli("info breakpoints").unwrap(), "Num Type\n");
+        assert!(controller.next_event(Duration::from_millis(10)).is_some());
+
+        controller.execute(MiCommand::exec_run()).unwrap();
+        match controller.next_event(Duration::from_secs(1)) {
+            Some(Event::Record(OOB::AsyncRecord(AsyncOutput::ExeAsync(data)))) => {
+                assert_eq!(data.1, OutputClass::Stopped)
+            }
+            other => panic!("unexpected event {:?}", other),
+        }
+        assert!(controller.next_event(Duration::from_millis(10)).is_none());
+        controller.exit().unwrap();
+    }
+}
@@ -1 +1,385 @@
+use crate::parser::output_types::Token;
 
+/// What `-target-select` connects to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Target {
+    /// A gdbserver or other remote stub at `host:port`, GDB disconnects once
+    /// the program exits.
+    Remote(String),
+    /// Like [`Target::Remote`] but the connection survives the program, so
+    /// it can be run again.
+    ExtendedRemote(String),
+    /// A core file, for looking at a crashed program after the fact.
+    Core(String),
+}
+
+/// A GDB/MI input command, rendered as `-operation [options] [--] [parameters]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MiCommand {
+    operation: String,
+    thread: Option<u32>,
+    thread_group: Option<String>,
+    options: Vec<String>,
+    parameters: Vec<String>,
+    // Sent as is after the options, for commands GDB hands to the CLI
+    // without parsing
+    raw: Option<String>,
+}
+
+impl MiCommand {
+    pub fn new<S: Into<String>>(operation: S) -> Self {
+        Self {
+            operation: operation.into(),
+            thread: None,
+            thread_group: None,
+            options: Vec::new(),
+            parameters: Vec::new(),
+            raw: None,
+        }
+    }
+
+    /// Runs the command in the context of thread `id` (`--thread`), rather
+    /// than the selected one.
+    pub fn thread(mut self, id: u32) -> Self {
+        self.thread = Some(id);
+        self
+    }
+
+    /// Runs the command in the context of thread group `id`
+    /// (`--thread-group`), i.e. `i1`.
+    pub fn thread_group<S: Into<String>>(mut self, id: S) -> Self {
+        self.thread_group = Some(id.into());
+        self
+    }
+
+    pub fn thread_id(&self) -> Option<u32> {
+        self.thread
+    }
+
+    /// Adds an option, the leading `-` is expected to be part of `option`.
+    pub fn option<S: Into<String>>(mut self, option: S) -> Self {
+        self.options.push(option.into());
+        self
+    }
+
+    /// Adds an option followed by its value, i.e. `--frame 0`.
+    pub fn option_value<S: Into<String>, V: Into<String>>(self, option: S, value: V) -> Self {
+        self.option(option).option(value)
+    }
+
+    pub fn parameter<S: Into<String>>(mut self, parameter: S) -> Self {
+        self.parameters.push(parameter.into());
+        self
+    }
+
+    pub fn operation(&self) -> &str {
+        &self.operation
+    }
+
+    /// Whether GDB can only run the command once the target stopped, in
+    /// all-stop mode. Everything that reads or writes target memory,
+    /// registers or frames falls in here, as do the execution commands
+    /// besides `-exec-interrupt`.
+    pub fn requires_stopped(&self) -> bool {
+        const NEEDS_STOPPED: [&str; 6] = ["break-", "data-", "dprintf-", "exec-", "stack-", "var-"];
+        self.operation != "exec-interrupt"
+            && NEEDS_STOPPED
+                .iter()
+                .any(|prefix| self.operation.starts_with(prefix))
+    }
+
+    /// Whether the command starts, stops or moves the program, which a core
+    /// dump can't do.
+    pub fn is_execution(&self) -> bool {
+        self.operation.starts_with("exec-")
+    }
+
+    /// Renders the command as a line ready to be written to GDB.
+    pub fn to_mi(&self, token: Token) -> String {
+        let mut line = format!("{}-{}", token.0, self.operation);
+        // General options go before the command specific ones
+        if let Some(thread) = self.thread {
+            line.push_str(&format!(" --thread {}", thread));
+        }
+        if let Some(group) = &self.thread_group {
+            line.push_str(" --thread-group ");
+            line.push_str(&quote(group));
+        }
+        for option in &self.options {
+            line.push(' ');
+            line.push_str(&quote(option));
+        }
+        if let Some(raw) = &self.raw {
+            line.push(' ');
+            line.push_str(raw);
+        }
+        if !self.parameters.is_empty() {
+            if self.parameters.iter().any(|p| p.starts_with('-')) {
+                line.push_str(" --");
+            }
+            for parameter in &self.parameters {
+                line.push(' ');
+                line.push_str(&quote(parameter));
+            }
+        }
+        line.push('\n');
+        line
+    }
+
+    pub fn exec_run() -> Self {
+        Self::new("exec-run")
+    }
+
+    pub fn exec_continue() -> Self {
+        Self::new("exec-continue")
+    }
+
+    pub fn exec_interrupt() -> Self {
+        Self::new("exec-interrupt")
+    }
+
+    pub fn exec_next() -> Self {
+        Self::new("exec-next")
+    }
+
+    pub fn exec_step() -> Self {
+        Self::new("exec-step")
+    }
+
+    /// Sets the program's arguments for the next run.
+    ///
+    /// GDB passes the text on to the shell that starts the program, so each
+    /// argument is quoted for the shell, and no `--` is added since the
+    /// program would get it too. Newlines can't be passed.
+    pub fn exec_arguments<I, S>(args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let args: Vec<_> = args.into_iter().map(|a| shell_quote(a.as_ref())).collect();
+        let mut command = Self::new("exec-arguments");
+        if !args.is_empty() {
+            command.raw = Some(args.join(" "));
+        }
+        command
+    }
+
+    pub fn exec_finish() -> Self {
+        Self::new("exec-finish")
+    }
+
+    pub fn file_exec_and_symbols<S: Into<String>>(path: S) -> Self {
+        Self::new("file-exec-and-symbols").parameter(path)
+    }
+
+    pub fn break_insert<S: Into<String>>(location: S) -> Self {
+        Self::new("break-insert").parameter(location)
+    }
+
+    pub fn break_delete(number: u32) -> Self {
+        Self::new("break-delete").parameter(number.to_string())
+    }
+
+    pub fn thread_info() -> Self {
+        Self::new("thread-info")
+    }
+
+    pub fn stack_list_frames() -> Self {
+        Self::new("stack-list-frames")
+    }
+
+    /// Lists the arguments and locals of frame `level`, with the values of
+    /// those that aren't structs, arrays or unions.
+    pub fn stack_list_variables(level: u32) -> Self {
+        Self::new("stack-list-variables")
+            .option_value("--frame", level.to_string())
+            .option("--simple-values")
+    }
+
+    pub fn gdb_set<S: Into<String>, V: Into<String>>(variable: S, value: V) -> Self {
+        Self::new("gdb-set").parameter(variable).parameter(value)
+    }
+
+    /// Runs `command` through another interpreter, i.e. `console` for CLI
+    /// commands.
+    pub fn interpreter_exec<S: Into<String>, C: Into<String>>(interpreter: S, command: C) -> Self {
+        Self::new("interpreter-exec")
+            .parameter(interpreter)
+            .parameter(command)
+    }
+
+    pub fn target_select(target: &Target) -> Self {
+        let command = Self::new("target-select");
+        match target {
+            Target::Remote(address) => command.parameter("remote").parameter(address.as_str()),
+            Target::ExtendedRemote(address) => command
+                .parameter("extended-remote")
+                .parameter(address.as_str()),
+            Target::Core(path) => command.parameter("core").parameter(path.as_str()),
+        }
+    }
+
+    /// Copies `host_file` to the remote target.
+    pub fn target_file_put<H: Into<String>, T: Into<String>>(host_file: H, target_file: T) -> Self {
+        Self::new("target-file-put")
+            .parameter(host_file)
+            .parameter(target_file)
+    }
+
+    /// Copies `target_file` from the remote target.
+    pub fn target_file_get<T: Into<String>, H: Into<String>>(target_file: T, host_file: H) -> Self {
+        Self::new("target-file-get")
+            .parameter(target_file)
+            .parameter(host_file)
+    }
+
+    pub fn target_file_delete<T: Into<String>>(target_file: T) -> Self {
+        Self::new("target-file-delete").parameter(target_file)
+    }
+
+    pub fn target_attach(pid: u32) -> Self {
+        Self::new("target-attach").parameter(pid.to_string())
+    }
+
+    pub fn inferior_tty_set<S: Into<String>>(tty: S) -> Self {
+        Self::new("inferior-tty-set").parameter(tty)
+    }
+
+    pub fn gdb_exit() -> Self {
+        Self::new("gdb-exit")
+    }
+}
+
+// Arguments with spaces or quotes must be sent as MI c-strings
+fn quote(arg: &str) -> String {
+    if !arg.is_empty() && !arg.contains(|c: char| c.is_whitespace() || c == '"' || c == '\\') {
+        return arg.to_owned();
+    }
+    let mut quoted = String::with_capacity(arg.len() + 2);
+    quoted.push('"');
+    for c in arg.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+// Double quotes keep everything but these literal in the shell
+fn shell_quote(arg: &str) -> String {
+    let plain = |c: char| c.is_ascii_alphanumeric() || "-_./=:,+@%".contains(c);
+    if !arg.is_empty() && arg.chars().all(plain) {
+        return arg.to_owned();
+    }
+    let mut quoted = String::with_capacity(arg.len() + 2);
+    quoted.push('"');
+    for c in arg.chars() {
+        if matches!(c, '"' | '\\' | '$' | '`') {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_mi() {
+        assert_eq!(MiCommand::exec_run().to_mi(Token(1)), "1-exec-run\n");
+        assert_eq!(
+            MiCommand::break_insert("main.c:12")
+                .option("-t")
+                .to_mi(Token(7)),
+            "7-break-insert -t main.c:12\n"
+        );
+        assert_eq!(
+            MiCommand::new("stack-list-variables")
+                .option_value("--frame", "0")
+                .option("--all-values")
+                .to_mi(Token(2)),
+            "2-stack-list-variables --frame 0 --all-values\n"
+        );
+        assert_eq!(
+            MiCommand::new("data-evaluate-expression")
+                .parameter("-1")
+                .to_mi(Token(3)),
+            "3-data-evaluate-expression -- -1\n"
+        );
+        assert_eq!(
+            MiCommand::stack_list_frames()
+                .option("--no-frame-filters")
+                .thread(3)
+                .to_mi(Token(4)),
+            "4-stack-list-frames --thread 3 --no-frame-filters\n"
+        );
+        assert_eq!(
+            MiCommand::exec_continue()
+                .thread_group("i2")
+                .to_mi(Token(5)),
+            "5-exec-continue --thread-group i2\n"
+        );
+        assert_eq!(
+            MiCommand::stack_list_variables(1).thread(2).to_mi(Token(6)),
+            "6-stack-list-variables --thread 2 --frame 1 --simple-values\n"
+        );
+    }
+
+    #[test]
+    fn test_exec_arguments() {
+        assert_eq!(
+            MiCommand::exec_arguments(["-v", "a b", "", "$HOME \"x\""]).to_mi(Token(1)),
+            "1-exec-arguments -v \"a b\" \"\" \"\\$HOME \\\"x\\\"\"\n"
+        );
+        assert_eq!(
+            MiCommand::exec_arguments(Vec::<String>::new()).to_mi(Token(2)),
+            "2-exec-arguments\n"
+        );
+    }
+
+    #[test]
+    fn test_target_commands() {
+        assert_eq!(
+            MiCommand::target_select(&Target::ExtendedRemote("localhost:2345".to_owned()))
+                .to_mi(Token(1)),
+            "1-target-select extended-remote localhost:2345\n"
+        );
+        assert_eq!(
+            MiCommand::target_file_put("build/app", "/tmp/app").to_mi(Token(2)),
+            "2-target-file-put build/app /tmp/app\n"
+        );
+        assert_eq!(
+            MiCommand::target_select(&Target::Core("/tmp/core dumps/core.1".to_owned()))
+                .to_mi(Token(3)),
+            "3-target-select core \"/tmp/core dumps/core.1\"\n"
+        );
+    }
+
+    #[test]
+    fn test_requires_stopped() {
+        assert!(MiCommand::break_insert("main").requires_stopped());
+        assert!(MiCommand::stack_list_frames().requires_stopped());
+        assert!(MiCommand::exec_continue().requires_stopped());
+        assert!(!MiCommand::exec_interrupt().requires_stopped());
+        assert!(!MiCommand::thread_info().requires_stopped());
+        assert!(!MiCommand::gdb_exit().requires_stopped());
+    }
+
+    #[test]
+    fn test_quote() {
+        assert_eq!(quote("plain"), "plain");
+        assert_eq!(quote(""), "\"\"");
+        assert_eq!(
+            quote("/tmp/my dir/a \"b\".c"),
+            "\"/tmp/my dir/a \\\"b\\\".c\""
+        );
+    }
+}
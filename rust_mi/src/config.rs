@@ -0,0 +1,283 @@
+//! How to launch GDB.
+
+use std::{ffi::{OsStr, OsString},
+          io,
+          path::{Path, PathBuf},
+          process::Stdio};
+
+use tokio::process::Command;
+
+use crate::{transport::ProcessTransport,
+            types::{Error, Result},
+            MIController};
+
+// MI3 needs at least GDB 9.1
+const MIN_VERSION: (u32, u32) = (9, 1);
+
+/// A GDB version as reported by `gdb --version`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GdbVersion {
+    pub major: u32,
+    pub minor: u32,
+    /// The first line of `gdb --version`, i.e. `GNU gdb (GDB) 12.1`.
+    pub banner: String,
+}
+
+impl GdbVersion {
+    pub fn parse(output: &str) -> Option<GdbVersion> {
+        let banner = output.lines().next()?.trim();
+        // The version is the last word, distros append their own suffixes
+        let version = banner.split_whitespace().last()?;
+        let mut numbers = version
+            .split(|c: char| !c.is_ascii_digit())
+            .map(str::parse::<u32>);
+        Some(GdbVersion {
+            major: numbers.next()?.ok()?,
+            minor: numbers.next()?.ok()?,
+            banner: banner.to_owned(),
+        })
+    }
+}
+
+/// Builder for the GDB process behind an [`MIController`].
+///
+/// By default runs `gdb` from `PATH` with `-nx`, so no init file is read.
+#[derive(Debug, Clone)]
+pub struct GdbConfig {
+    program: PathBuf,
+    no_init: bool,
+    no_home_init: bool,
+    init_scripts: Vec<PathBuf>,
+    data_directory: Option<PathBuf>,
+    env_clear: bool,
+    env: Vec<(OsString, Option<OsString>)>,
+    args: Vec<OsString>,
+}
+
+impl Default for GdbConfig {
+    fn default() -> Self {
+        Self {
+            program: PathBuf::from("gdb"),
+            no_init: true,
+            no_home_init: false,
+            init_scripts: Vec::new(),
+            data_directory: None,
+            env_clear: false,
+            env: Vec::new(),
+            args: Vec::new(),
+        }
+    }
+}
+
+impl GdbConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The GDB executable, i.e. `gdb-multiarch` or a full path.
+    pub fn program<P: Into<PathBuf>>(mut self, program: P) -> Self {
+        self.program = program.into();
+        self
+    }
+
+    /// Skips every init file (`-nx`), on by default.
+    pub fn no_init(mut self, no_init: bool) -> Self {
+        self.no_init = no_init;
+        self
+    }
+
+    /// Skips only `~/.gdbinit` (`-nh`).
+    pub fn no_home_init(mut self, no_home_init: bool) -> Self {
+        self.no_home_init = no_home_init;
+        self
+    }
+
+    /// Runs a script once GDB is up (`-x`), even with `-nx`.
+    pub fn init_script<P: Into<PathBuf>>(mut self, script: P) -> Self {
+        self.init_scripts.push(script.into());
+        self
+    }
+
+    /// Where GDB finds its python modules and syntax files
+    /// (`--data-directory`).
+    pub fn data_directory<P: Into<PathBuf>>(mut self, directory: P) -> Self {
+        self.data_directory = Some(directory.into());
+        self
+    }
+
+    /// Starts GDB from an empty environment, variables set with
+    /// [`env`](GdbConfig::env) still apply.
+    pub fn env_clear(mut self) -> Self {
+        self.env_clear = true;
+        self.env.clear();
+        self
+    }
+
+    pub fn env<K: AsRef<OsStr>, V: AsRef<OsStr>>(mut self, key: K, value: V) -> Self {
+        self.env
+            .push((key.as_ref().to_owned(), Some(value.as_ref().to_owned())));
+        self
+    }
+
+    pub fn env_remove<K: AsRef<OsStr>>(mut self, key: K) -> Self {
+        self.env.push((key.as_ref().to_owned(), None));
+        self
+    }
+
+    /// Extra arguments, passed after the ones the config knows about.
+    pub fn arg<S: AsRef<OsStr>>(mut self, arg: S) -> Self {
+        self.args.push(arg.as_ref().to_owned());
+        self
+    }
+
+    pub fn args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        self.args
+            .extend(args.into_iter().map(|a| a.as_ref().to_owned()));
+        self
+    }
+
+    pub fn program_path(&self) -> &Path {
+        &self.program
+    }
+
+    /// The command line GDB is started with in MI mode.
+    pub fn command(&self) -> Command {
+        let mut command = self.base_command();
+        // Asynchronous MI so running targets can be interrupted, set before
+        // anything else runs
+        command.args(["--interpreter=mi3", "-q", "-iex", "set mi-async on"]);
+        if self.no_init {
+            command.arg("-nx");
+        }
+        if self.no_home_init {
+            command.arg("-nh");
+        }
+        if let Some(directory) = &self.data_directory {
+            command.arg("--data-directory").arg(directory);
+        }
+        for script in &self.init_scripts {
+            command.arg("-x").arg(script);
+        }
+        command.args(&self.args);
+        command
+    }
+
+    /// Runs `gdb --version`.
+    pub async fn version(&self) -> Result<GdbVersion> {
+        let output = self
+            .base_command()
+            .arg("--version")
+            .stdin(Stdio::null())
+            .output()
+            .await
+            .map_err(|e| self.spawn_error(e))?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        GdbVersion::parse(&stdout).ok_or_else(|| Error::UnsupportedGdb {
+            program: self.program.clone(),
+            version: stdout.lines().next().unwrap_or_default().to_owned(),
+        })
+    }
+
+    /// Checks GDB is there and recent enough, then starts it.
+    pub async fn spawn(&self) -> Result<MIController> {
+        let version = self.version().await?;
+        if (version.major, version.minor) < MIN_VERSION {
+            return Err(Error::UnsupportedGdb {
+                program: self.program.clone(),
+                version: version.banner,
+            });
+        }
+        let transport = ProcessTransport::gdb(self).map_err(|e| self.spawn_error(e))?;
+        let controller = MIController::with_transport(transport)?;
+        controller.assume_mi_async();
+        Ok(controller)
+    }
+
+    fn base_command(&self) -> Command {
+        let mut command = Command::new(&self.program);
+        if self.env_clear {
+            command.env_clear();
+        }
+        for (key, value) in &self.env {
+            match value {
+                Some(value) => command.env(key, value),
+                None => command.env_remove(key),
+            };
+        }
+        command
+    }
+
+    fn spawn_error(&self, e: io::Error) -> Error {
+        match e.kind() {
+            io::ErrorKind::NotFound => Error::GdbNotFound(self.program.clone()),
+            _ => Error::Io(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_version() {
+        let version = GdbVersion::parse("GNU gdb (GDB) 12.1\nCopyright (C) 2022").unwrap();
+        assert_eq!((version.major, version.minor), (12, 1));
+        assert_eq!(version.banner, "GNU gdb (GDB) 12.1");
+        let version = GdbVersion::parse("GNU gdb (Ubuntu 12.1-0ubuntu1~22.04) 12.1").unwrap();
+        assert_eq!((version.major, version.minor), (12, 1));
+        let version = GdbVersion::parse("GNU gdb (GDB) Fedora Linux 13.2-3.fc38").unwrap();
+        assert_eq!((version.major, version.minor), (13, 2));
+        assert!(GdbVersion::parse("lldb-1500.0.22.8").is_none());
+        assert!(GdbVersion::parse("").is_none());
+    }
+
+    #[test]
+    fn test_command() {
+        let config = GdbConfig::new()
+            .program("gdb-multiarch")
+            .no_init(false)
+            .no_home_init(true)
+            .data_directory("/opt/gdb/share/gdb")
+            .init_script("pretty.py")
+            .env("TERM", "dumb")
+            .args(["--nw", "-iex"])
+            .arg("set auto-load off");
+        let command = config.command();
+        let command = command.as_std();
+        assert_eq!(command.get_program(), "gdb-multiarch");
+        let args: Vec<_> = command.get_args().collect();
+        assert_eq!(
+            args,
+            [
+                "--interpreter=mi3",
+                "-q",
+                "-iex",
+                "set mi-async on",
+                "-nh",
+                "--data-directory",
+                "/opt/gdb/share/gdb",
+                "-x",
+                "pretty.py",
+                "--nw",
+                "-iex",
+                "set auto-load off",
+            ]
+        );
+        let env: Vec<_> = command.get_envs().collect();
+        assert_eq!(env, [(OsStr::new("TERM"), Some(OsStr::new("dumb")))]);
+    }
+
+    #[tokio::test]
+    async fn test_missing_gdb() {
+        let config = GdbConfig::new().program("/nonexistent/gdb");
+        match config.spawn().await {
+            Err(Error::GdbNotFound(program)) => assert_eq!(program, Path::new("/nonexistent/gdb")),
+            other => panic!("unexpected outcome {:?}", other),
+        }
+    }
+}
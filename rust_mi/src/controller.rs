@@ -0,0 +1,1117 @@
+use std::{collections::HashMap,
+          fmt, future,
+          sync::{atomic::{AtomicBool, AtomicU32, Ordering},
+                 Arc, Mutex as StdMutex},
+          time::Duration};
+
+use tokio::{io::{AsyncBufReadExt, AsyncRead, AsyncWriteExt, BufReader},
+            sync::{broadcast, oneshot, Mutex, Notify},
+            task::JoinHandle};
+
+#[cfg(unix)]
+use crate::pty::Pty;
+use crate::{commands::{MiCommand, Target},
+            config::GdbConfig,
+            parser::{output_types::{Output, OutputClass, OutputData, StreamOutput, Token, OOB},
+                     parse_mi_output},
+            state::{ExecutionState, ThreadState},
+            transcript::{Direction, Transcript},
+            transport::{BoxFuture, BoxedWriter, MiTransport},
+            types::{BackendExit, CoreDump, Error, Result}};
+
+// How many out of band records a slow subscriber can fall behind before
+// missing some
+const EVENTS_CAPACITY: usize = 256;
+
+// How long to wait for the last bits of output once GDB is gone
+const EXIT_GRACE: Duration = Duration::from_millis(500);
+
+/// What [`MIController::events`] subscribers get.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    /// An async or stream record.
+    Record(OOB<'static>),
+    /// GDB went away, always the last event.
+    Exited(BackendExit),
+}
+
+type Answer = Result<OutputData<'static>>;
+
+// What GDB printed while a command ran, see `MIController::execute_capturing`
+#[derive(Debug, Default)]
+struct Captured {
+    console: String,
+    stopped_thread: Option<u32>,
+}
+
+// State shared between the controller and its reader task
+#[derive(Debug)]
+struct Shared {
+    pending: StdMutex<HashMap<Token, oneshot::Sender<Answer>>>,
+    events: broadcast::Sender<Event>,
+    transcript: StdMutex<Option<Transcript>>,
+    exit: StdMutex<Option<BackendExit>>,
+    state: StdMutex<ExecutionState>,
+    state_changed: Notify,
+    mode: StdMutex<StopMode>,
+    captured: StdMutex<Option<Captured>>,
+    // Whether GDB reads commands while the target runs
+    mi_async: AtomicBool,
+}
+
+impl Shared {
+    fn new() -> Arc<Self> {
+        let (events, _) = broadcast::channel(EVENTS_CAPACITY);
+        Arc::new(Shared {
+            pending: StdMutex::default(),
+            events,
+            transcript: StdMutex::default(),
+            exit: StdMutex::default(),
+            state: StdMutex::default(),
+            state_changed: Notify::new(),
+            mode: StdMutex::default(),
+            captured: StdMutex::default(),
+            mi_async: AtomicBool::new(false),
+        })
+    }
+
+    fn record(&self, direction: Direction, line: &str) {
+        if let Some(transcript) = &*self.transcript.lock().unwrap() {
+            transcript.record(direction, line);
+        }
+    }
+
+    fn exited(&self) -> Option<BackendExit> {
+        self.exit.lock().unwrap().clone()
+    }
+
+    fn dispatch(&self, line: &str) {
+        self.record(Direction::Received, line);
+        match parse_mi_output(line) {
+            Ok((_, Output::ResultRecord(record))) => {
+                let sender = record
+                    .0
+                    .and_then(|t| self.pending.lock().unwrap().remove(&t));
+                if let Some(sender) = sender {
+                    let _ = sender.send(Ok(record.into_owned()));
+                }
+            }
+            Ok((_, Output::OOBRecord(record))) => {
+                // Updated first so subscribers see the state the record led to
+                if let OOB::AsyncRecord(record) = &record {
+                    self.state.lock().unwrap().update(record);
+                    self.state_changed.notify_waiters();
+                }
+                self.capture(&record);
+                // No subscribers is not an error
+                let _ = self.events.send(Event::Record(record.into_owned()));
+            }
+            _ => {}
+        }
+    }
+
+    // Kept here rather than left to subscribers, which can lag behind and
+    // miss records
+    fn capture(&self, record: &OOB<'_>) {
+        let mut captured = self.captured.lock().unwrap();
+        let captured = match captured.as_mut() {
+            Some(captured) => captured,
+            None => return,
+        };
+        match record {
+            OOB::StreamRecord(StreamOutput::Console(text)) => captured.console.push_str(text),
+            OOB::AsyncRecord(record) if record.data().1 == OutputClass::Stopped => {
+                captured.stopped_thread = record
+                    .data()
+                    .get_str("thread-id")
+                    .and_then(|id| id.parse().ok());
+            }
+            _ => {}
+        }
+    }
+
+    fn terminate(&self, exit: BackendExit) {
+        // Published before draining so `execute` either sees it or gets drained
+        *self.exit.lock().unwrap() = Some(exit.clone());
+        let pending: Vec<_> = self.pending.lock().unwrap().drain().collect();
+        for (_, sender) in pending {
+            let _ = sender.send(Err(Error::BackendExited(exit.clone())));
+        }
+        self.state_changed.notify_waiters();
+        let _ = self.events.send(Event::Exited(exit));
+    }
+
+    // Whether `command` has to wait for the target to stop. In non-stop mode
+    // only the thread it names matters, without one GDB picks the selected
+    // thread and we can't tell.
+    fn blocked(&self, command: &MiCommand) -> bool {
+        if !command.requires_stopped() {
+            return false;
+        }
+        let state = self.state.lock().unwrap();
+        match (*self.mode.lock().unwrap(), command.thread_id()) {
+            (StopMode::AllStop, _) => state.any_running(),
+            (StopMode::NonStop, Some(thread)) => state
+                .thread(thread)
+                .is_some_and(|t| t.state == ThreadState::Running),
+            (StopMode::NonStop, None) => false,
+        }
+    }
+
+    // Returns once `command` can go through or GDB is gone
+    async fn wait_unblocked(&self, command: &MiCommand) {
+        loop {
+            let changed = self.state_changed.notified();
+            if !self.blocked(command) || self.exited().is_some() {
+                return;
+            }
+            changed.await;
+        }
+    }
+}
+
+/// Cancels the [`MIController::execute_with`] calls it was handed to.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken {
+    inner: Arc<CancelInner>,
+}
+
+#[derive(Debug, Default)]
+struct CancelInner {
+    cancelled: AtomicBool,
+    notify: Notify,
+}
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::SeqCst);
+        self.inner.notify.notify_waiters();
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    async fn cancelled(&self) {
+        let notified = self.inner.notify.notified();
+        if !self.is_cancelled() {
+            notified.await;
+        }
+    }
+}
+
+/// How GDB stops threads, see [`MIController::set_stop_mode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StopMode {
+    /// Every thread stops when one does, GDB's default.
+    #[default]
+    AllStop,
+    /// Threads stop and resume on their own, with `mi-async` on.
+    NonStop,
+}
+
+/// What to do with a command that needs a stopped target while it's running,
+/// see [`MiCommand::requires_stopped`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WhileRunning {
+    /// Hold the command back until the target stops.
+    #[default]
+    Queue,
+    /// Interrupt the target, run the command and resume it. Needs `mi-async`,
+    /// without it GDB doesn't read the interrupt until the target stops, so
+    /// the command is queued instead.
+    Interrupt,
+    /// Send the command anyway and let GDB complain.
+    Send,
+    /// Fail right away with [`Error::Running`], for callers that can't be
+    /// held up.
+    Fail,
+}
+
+/// Per call knobs for [`MIController::execute_with`].
+#[derive(Debug, Clone, Default)]
+pub struct ExecuteOptions {
+    /// Overrides the controller wide timeout set with
+    /// [`MIController::set_timeout`].
+    pub timeout: Option<Duration>,
+    pub cancel: Option<CancelToken>,
+    /// Sends `-exec-interrupt` when the command times out or is cancelled,
+    /// for commands that only return once the target stops. Only with
+    /// `mi-async` on, see [`WhileRunning::Interrupt`].
+    pub interrupt: bool,
+    pub while_running: WhileRunning,
+}
+
+impl ExecuteOptions {
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn cancel(mut self, cancel: CancelToken) -> Self {
+        self.cancel = Some(cancel);
+        self
+    }
+
+    pub fn interrupt(mut self) -> Self {
+        self.interrupt = true;
+        self
+    }
+
+    pub fn while_running(mut self, policy: WhileRunning) -> Self {
+        self.while_running = policy;
+        self
+    }
+}
+
+// Forgets about a command once its caller stops waiting, however that happens
+struct PendingGuard<'a> {
+    shared: &'a Shared,
+    token: Token,
+}
+
+impl Drop for PendingGuard<'_> {
+    fn drop(&mut self) {
+        self.shared.pending.lock().unwrap().remove(&self.token);
+    }
+}
+
+// Stops capturing output once the caller is done, however that happens
+struct CaptureGuard<'a>(&'a Shared);
+
+impl CaptureGuard<'_> {
+    fn take(&self) -> Captured {
+        self.0.captured.lock().unwrap().take().unwrap_or_default()
+    }
+}
+
+impl Drop for CaptureGuard<'_> {
+    fn drop(&mut self) {
+        self.take();
+    }
+}
+
+/// Drives a GDB instance through the MI3 interpreter.
+///
+/// Commands are tagged with a [`Token`] and each call to
+/// [`execute`](MIController::execute) resolves when GDB answers with the result
+/// record carrying the same token. Everything else GDB prints (async and
+/// stream records) is published through [`events`](MIController::events).
+///
+/// Commands that need a stopped target are held back while any thread runs,
+/// or while the thread they name runs in non-stop mode, see [`WhileRunning`].
+///
+/// When GDB dies every command still waiting fails with
+/// [`Error::BackendExited`] and subscribers get a final [`Event::Exited`].
+pub struct MIController {
+    // Only touched on exit, the lock makes the controller `Sync`
+    transport: StdMutex<Box<dyn MiTransport>>,
+    stdin: Mutex<BoxedWriter>,
+    shared: Arc<Shared>,
+    next_token: AtomicU32,
+    timeout: StdMutex<Option<Duration>>,
+    gate: Mutex<()>,
+    // One command's output is captured at a time
+    capturing: Mutex<()>,
+    post_mortem: AtomicBool,
+    reader: JoinHandle<()>,
+}
+
+impl MIController {
+    /// Spawns `gdb --interpreter=mi3 -q -nx` from `PATH`, must be called from a
+    /// tokio runtime. See [`GdbConfig`] for other setups.
+    pub async fn new() -> Result<MIController> {
+        GdbConfig::default().spawn().await
+    }
+
+    /// Starts talking MI through `transport`, must be called from a tokio
+    /// runtime.
+    pub fn with_transport<T: MiTransport + 'static>(mut transport: T) -> Result<MIController> {
+        let (stdout, stdin) = transport.take_io()?;
+        let shared = Shared::new();
+        let exited = transport.exited();
+        let reader = tokio::spawn(read_loop(stdout, shared.clone(), exited));
+        Ok(MIController {
+            transport: StdMutex::new(Box::new(transport)),
+            stdin: Mutex::new(stdin),
+            shared,
+            next_token: AtomicU32::new(1),
+            timeout: StdMutex::default(),
+            gate: Mutex::new(()),
+            capturing: Mutex::new(()),
+            post_mortem: AtomicBool::new(false),
+            reader,
+        })
+    }
+
+    /// Sends `command` and waits for its result record.
+    ///
+    /// An `^error` answer is turned into [`Error::Mi`].
+    pub async fn execute(&self, command: MiCommand) -> Result<OutputData<'static>> {
+        self.execute_with(command, ExecuteOptions::default()).await
+    }
+
+    /// Like [`execute`](MIController::execute) but gives up with
+    /// [`Error::Timeout`] or [`Error::Cancelled`] as set in `options`.
+    ///
+    /// A late answer to an abandoned command is dropped.
+    pub async fn execute_with(
+        &self,
+        command: MiCommand,
+        options: ExecuteOptions,
+    ) -> Result<OutputData<'static>> {
+        if command.is_execution() && self.is_post_mortem() {
+            return Err(Error::PostMortem);
+        }
+        let timeout = options.timeout.or(*self.timeout.lock().unwrap());
+        let expired = async {
+            match timeout {
+                Some(timeout) => tokio::time::sleep(timeout).await,
+                None => future::pending().await,
+            }
+        };
+        let cancelled = async {
+            match &options.cancel {
+                Some(cancel) => cancel.cancelled().await,
+                None => future::pending().await,
+            }
+        };
+        let outcome = tokio::select! {
+            answer = self.dispatch(command, options.while_running) => answer,
+            _ = expired => Err(Error::Timeout(timeout.unwrap_or_default())),
+            _ = cancelled => Err(Error::Cancelled),
+        };
+        if options.interrupt
+            && self.is_mi_async()
+            && matches!(outcome, Err(Error::Timeout(_) | Error::Cancelled))
+        {
+            // Nobody waits for this answer, the reader drops it
+            let interrupt = MiCommand::exec_interrupt().to_mi(self.next_token());
+            let _ = self.write(&interrupt).await;
+        }
+        let record = outcome?;
+        match record.1 {
+            OutputClass::Error => Err(Error::Mi {
+                msg: record.get_str("msg").unwrap_or_default().to_owned(),
+                code: record.get_str("code").map(str::to_owned),
+            }),
+            _ => Ok(record),
+        }
+    }
+
+    /// Runs a CLI command, i.e. `info registers`, and returns what it printed
+    /// on the console.
+    pub async fn cli(&self, command: &str) -> Result<String> {
+        let (_, captured) = self
+            .execute_capturing(MiCommand::interpreter_exec("console", command))
+            .await?;
+        Ok(captured.console)
+    }
+
+    // Runs `command`, collecting the console output and `*stopped` records
+    // that come before its result
+    async fn execute_capturing(
+        &self,
+        command: MiCommand,
+    ) -> Result<(OutputData<'static>, Captured)> {
+        let _capturing = self.capturing.lock().await;
+        *self.shared.captured.lock().unwrap() = Some(Captured::default());
+        let guard = CaptureGuard(&self.shared);
+        let record = self.execute(command).await?;
+        Ok((record, guard.take()))
+    }
+
+    /// Sets the timeout used by commands that don't pick their own, `None`
+    /// waits forever.
+    pub fn set_timeout(&self, timeout: Option<Duration>) {
+        *self.timeout.lock().unwrap() = timeout;
+    }
+
+    /// Subscribes to the async (`*stopped`, `=thread-created`, ...) and stream
+    /// (`~`, `@`, `&`) records GDB emits.
+    ///
+    /// Each receiver sees every record published after it was created.
+    pub fn events(&self) -> broadcast::Receiver<Event> {
+        self.shared.events.subscribe()
+    }
+
+    /// Switches GDB between all-stop and non-stop mode, turning `mi-async` on
+    /// for both so running targets can be interrupted. Has to happen before
+    /// the target is started.
+    pub async fn set_stop_mode(&self, mode: StopMode) -> Result<()> {
+        self.execute(MiCommand::gdb_set("mi-async", "on")).await?;
+        self.shared.mi_async.store(true, Ordering::SeqCst);
+        let non_stop = match mode {
+            StopMode::AllStop => "off",
+            StopMode::NonStop => "on",
+        };
+        self.execute(MiCommand::gdb_set("non-stop", non_stop))
+            .await?;
+        *self.shared.mode.lock().unwrap() = mode;
+        Ok(())
+    }
+
+    /// Whether GDB takes commands while the target runs, either because
+    /// [`GdbConfig`] started it that way or after
+    /// [`set_stop_mode`](MIController::set_stop_mode).
+    pub fn is_mi_async(&self) -> bool {
+        self.shared.mi_async.load(Ordering::SeqCst)
+    }
+
+    // For GDBs started with `mi-async` already on
+    pub(crate) fn assume_mi_async(&self) {
+        self.shared.mi_async.store(true, Ordering::SeqCst);
+    }
+
+    pub fn stop_mode(&self) -> StopMode {
+        *self.shared.mode.lock().unwrap()
+    }
+
+    /// Connects to a gdbserver or other remote stub. `sysroot` is where GDB
+    /// looks for the target's shared libraries, `target:` fetches them
+    /// through the connection.
+    pub async fn connect_remote(&self, target: &Target, sysroot: Option<&str>) -> Result<()> {
+        if let Some(sysroot) = sysroot {
+            self.execute(MiCommand::gdb_set("sysroot", sysroot)).await?;
+        }
+        self.execute(MiCommand::target_select(target)).await?;
+        Ok(())
+    }
+
+    /// Loads `executable` and the `core` it dumped for post-mortem debugging.
+    /// Threads, frames and variables can be inspected afterwards but
+    /// execution commands fail with [`Error::PostMortem`].
+    pub async fn load_core(&self, executable: &str, core: &str) -> Result<CoreDump> {
+        self.execute(MiCommand::file_exec_and_symbols(executable))
+            .await?;
+        // GDB explains how the program ended on the console while loading
+        let (_, captured) = self
+            .execute_capturing(MiCommand::target_select(&Target::Core(core.to_owned())))
+            .await?;
+        self.post_mortem.store(true, Ordering::SeqCst);
+        // Nothing runs in a core, whether or not GDB said so with a `*stopped`
+        self.shared
+            .state
+            .lock()
+            .unwrap()
+            .set_all(ThreadState::Stopped);
+        self.shared.state_changed.notify_waiters();
+        let mut dump = CoreDump {
+            thread: captured.stopped_thread,
+            ..CoreDump::default()
+        };
+        dump.parse_console(&captured.console);
+        Ok(dump)
+    }
+
+    /// Whether the target is a core dump.
+    pub fn is_post_mortem(&self) -> bool {
+        self.post_mortem.load(Ordering::SeqCst)
+    }
+
+    /// Uploads `host_file` to the remote target.
+    pub async fn put_file(&self, host_file: &str, target_file: &str) -> Result<()> {
+        self.execute(MiCommand::target_file_put(host_file, target_file))
+            .await?;
+        Ok(())
+    }
+
+    /// Downloads `target_file` from the remote target.
+    pub async fn get_file(&self, target_file: &str, host_file: &str) -> Result<()> {
+        self.execute(MiCommand::target_file_get(target_file, host_file))
+            .await?;
+        Ok(())
+    }
+
+    /// Allocates a pseudo terminal and has GDB run the inferior on it, the
+    /// returned handles carry the program's I/O.
+    #[cfg(unix)]
+    pub async fn open_inferior_tty(&self) -> Result<Pty> {
+        let pty = Pty::open()?;
+        let path = pty.path().to_string_lossy().into_owned();
+        self.execute(MiCommand::inferior_tty_set(path)).await?;
+        Ok(pty)
+    }
+
+    /// A snapshot of which threads and inferiors are running.
+    pub fn state(&self) -> ExecutionState {
+        self.shared.state.lock().unwrap().clone()
+    }
+
+    /// Whether thread `id` is known and stopped, i.e. safe to ask for frames.
+    pub fn is_thread_stopped(&self, id: u32) -> bool {
+        self.shared.state.lock().unwrap().is_thread_stopped(id)
+    }
+
+    /// How GDB went away, `None` while it's still around.
+    pub fn exit_status(&self) -> Option<BackendExit> {
+        self.shared.exited()
+    }
+
+    /// Starts logging every command sent and every line received, replacing
+    /// any transcript already being recorded.
+    pub fn start_recording(&self) -> Transcript {
+        let transcript = Transcript::new();
+        *self.shared.transcript.lock().unwrap() = Some(transcript.clone());
+        transcript
+    }
+
+    /// Stops logging and returns what was recorded, if anything.
+    pub fn stop_recording(&self) -> Option<Transcript> {
+        self.shared.transcript.lock().unwrap().take()
+    }
+
+    /// Asks GDB to quit and shuts the transport down.
+    pub async fn exit(mut self) -> Result<()> {
+        // GDB may go away before answering, that's fine
+        let _ = self.execute(MiCommand::gdb_exit()).await;
+        self.transport.get_mut().unwrap().shutdown().await?;
+        Ok(())
+    }
+
+    // Holds back commands GDB can't run while the target is running
+    async fn dispatch(&self, command: MiCommand, policy: WhileRunning) -> Answer {
+        if policy == WhileRunning::Send || !self.shared.blocked(&command) {
+            return self.send(&command).await;
+        }
+        if policy == WhileRunning::Fail {
+            return Err(Error::Running);
+        }
+        // Taken in FIFO order so queued commands keep their order
+        let _gate = self.gate.lock().await;
+        // A synchronous GDB wouldn't read the interrupt before the target
+        // stops anyway
+        let queue = policy == WhileRunning::Queue || !self.is_mi_async();
+        if queue || !self.shared.blocked(&command) {
+            self.shared.wait_unblocked(&command).await;
+            return self.send(&command).await;
+        }
+        // Only the thread in the way is stopped in non-stop mode
+        let (mut interrupt, mut resume) = (MiCommand::exec_interrupt(), MiCommand::exec_continue());
+        if let Some(thread) = command.thread_id() {
+            interrupt = interrupt.thread(thread);
+            resume = resume.thread(thread);
+        }
+        self.send(&interrupt).await?;
+        self.shared.wait_unblocked(&command).await;
+        let answer = self.send(&command).await;
+        // Resume whatever happened to the command
+        self.send(&resume).await?;
+        answer
+    }
+
+    async fn send(&self, command: &MiCommand) -> Answer {
+        let token = self.next_token();
+        let (tx, rx) = oneshot::channel();
+        // Register before writing so a fast answer can't beat us to the map
+        self.shared.pending.lock().unwrap().insert(token, tx);
+        let _guard = PendingGuard {
+            shared: &self.shared,
+            token,
+        };
+        if let Some(exit) = self.shared.exited() {
+            return Err(Error::BackendExited(exit));
+        }
+        if let Err(e) = self.write(&command.to_mi(token)).await {
+            // Writing to a dead process fails, its exit is more useful
+            return Err(self.shared.exited().map_or(e, Error::BackendExited));
+        }
+        rx.await.unwrap_or(Err(Error::Closed))
+    }
+
+    fn next_token(&self) -> Token {
+        Token(self.next_token.fetch_add(1, Ordering::Relaxed))
+    }
+
+    async fn write(&self, line: &str) -> Result<()> {
+        let mut stdin = self.stdin.lock().await;
+        self.shared
+            .record(Direction::Sent, line.trim_end_matches('\n'));
+        stdin.write_all(line.as_bytes()).await?;
+        stdin.flush().await?;
+        Ok(())
+    }
+}
+
+impl fmt::Debug for MIController {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MIController")
+            .field("transport", &*self.transport.lock().unwrap())
+            .field("next_token", &self.next_token)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for MIController {
+    fn drop(&mut self) {
+        self.reader.abort();
+    }
+}
+
+async fn read_loop<R: AsyncRead + Unpin>(
+    output: R,
+    shared: Arc<Shared>,
+    exited: Option<BoxFuture<'static, BackendExit>>,
+) {
+    let watched = exited.is_some();
+    let mut exited = exited.unwrap_or_else(|| Box::pin(future::pending()));
+    let mut lines = BufReader::new(output).lines();
+    let exit = loop {
+        tokio::select! {
+            line = lines.next_line() => match line {
+                Ok(Some(line)) => shared.dispatch(&line),
+                _ if watched => {
+                    break tokio::time::timeout(EXIT_GRACE, exited).await.unwrap_or_default()
+                }
+                _ => break BackendExit::default(),
+            },
+            exit = &mut exited => {
+                // Flush whatever GDB printed before going away
+                let _ = tokio::time::timeout(EXIT_GRACE, async {
+                    while let Ok(Some(line)) = lines.next_line().await {
+                        shared.dispatch(&line);
+                    }
+                })
+                .await;
+                break exit;
+            }
+        }
+    };
+    shared.terminate(exit);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+    use super::*;
+    use crate::{parser::output_types::AsyncOutput,
+                testing::FakeGdb,
+                transport::{MemoryTransport, ProcessTransport}};
+
+    #[tokio::test]
+    async fn test_read_loop() {
+        let output: &[u8] = b"=thread-group-added,id=\"i1\"\n\
+                              ~\"Reading symbols\\n\"\n\
+                              2^done,value=\"3\"\n\
+                              (gdb) \n";
+        let shared = Shared::new();
+        let (tx, rx) = oneshot::channel();
+        shared.pending.lock().unwrap().insert(Token(2), tx);
+        let mut subscriber = shared.events.subscribe();
+        read_loop(output, shared.clone(), None).await;
+
+        let record = rx.await.unwrap().unwrap();
+        assert_eq!(record.get_str("value"), Some("3"));
+        match subscriber.recv().await.unwrap() {
+            Event::Record(OOB::AsyncRecord(AsyncOutput::NotifyAsync(data))) => {
+                assert_eq!(data.1, OutputClass::ThreadGroupAdded)
+            }
+            other => panic!("unexpected record {:?}", other),
+        }
+        assert_eq!(
+            subscriber.recv().await.unwrap(),
+            Event::Record(OOB::StreamRecord(StreamOutput::Console(Cow::from(
+                "Reading symbols\n"
+            ))))
+        );
+        assert_eq!(
+            subscriber.recv().await.unwrap(),
+            Event::Exited(BackendExit::default())
+        );
+        assert!(shared.pending.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_execute() {
+        let (transport, peer) = MemoryTransport::pair();
+        let controller = MIController::with_transport(transport).unwrap();
+        let (peer_reader, mut peer_writer) = tokio::io::split(peer);
+        tokio::spawn(async move {
+            let mut commands = BufReader::new(peer_reader).lines();
+            while let Some(line) = commands.next_line().await.unwrap() {
+                let (token, command) = line.split_once('-').unwrap();
+                let answer = match command {
+                    "exec-run" => format!("{}^running\n*running,thread-id=\"all\"\n", token),
+                    _ => format!("{}^error,msg=\"Undefined MI command\"\n", token),
+                };
+                peer_writer.write_all(answer.as_bytes()).await.unwrap();
+            }
+        });
+
+        let mut events = controller.events();
+        let record = controller.execute(MiCommand::exec_run()).await.unwrap();
+        assert_eq!(record.1, OutputClass::Running);
+        match events.recv().await.unwrap() {
+            Event::Record(OOB::AsyncRecord(AsyncOutput::ExeAsync(data))) => {
+                assert_eq!(data.1, OutputClass::Running)
+            }
+            other => panic!("unexpected record {:?}", other),
+        }
+        match controller.execute(MiCommand::new("bogus")).await {
+            Err(Error::Mi { msg, .. }) => assert_eq!(msg, "Undefined MI command"),
+            other => panic!("unexpected answer {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_timeout() {
+        let (fake, controller) = FakeGdb::controller().unwrap();
+        // Never answered
+        fake.on("-var-list-children", Vec::<String>::new())
+            .on("-gdb-set", ["^done"]);
+        controller.set_stop_mode(StopMode::AllStop).await.unwrap();
+        let options = ExecuteOptions::default()
+            .timeout(Duration::from_millis(20))
+            .interrupt();
+        let command = MiCommand::new("var-list-children").parameter("var1");
+        match controller.execute_with(command.clone(), options).await {
+            Err(Error::Timeout(after)) => assert_eq!(after, Duration::from_millis(20)),
+            other => panic!("unexpected answer {:?}", other),
+        }
+        assert!(controller.shared.pending.lock().unwrap().is_empty());
+
+        controller.set_timeout(Some(Duration::from_millis(10)));
+        assert!(matches!(
+            controller.execute(command).await,
+            Err(Error::Timeout(_))
+        ));
+        fake.assert_received(&[
+            "-gdb-set mi-async on",
+            "-gdb-set non-stop off",
+            "-var-list-children var1",
+            "-exec-interrupt",
+            "-var-list-children var1",
+        ]);
+    }
+
+    #[tokio::test]
+    async fn test_cancel() {
+        let (fake, controller) = FakeGdb::controller().unwrap();
+        fake.on("-symbol-info-functions", Vec::<String>::new());
+        let cancel = CancelToken::new();
+        let options = ExecuteOptions::default().cancel(cancel.clone());
+        let canceller = async {
+            tokio::task::yield_now().await;
+            cancel.cancel();
+        };
+        let (outcome, _) = tokio::join!(
+            controller.execute_with(MiCommand::new("symbol-info-functions"), options),
+            canceller
+        );
+        assert!(matches!(outcome, Err(Error::Cancelled)));
+        assert!(controller.shared.pending.lock().unwrap().is_empty());
+
+        // Already cancelled tokens cancel straight away
+        let options = ExecuteOptions::default().cancel(cancel);
+        assert!(matches!(
+            controller
+                .execute_with(MiCommand::new("symbol-info-functions"), options)
+                .await,
+            Err(Error::Cancelled)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_backend_crash() {
+        let script = "read line; echo boom >&2; kill -SEGV $$";
+        let transport =
+            ProcessTransport::spawn(tokio::process::Command::new("sh").args(["-c", script]))
+                .unwrap();
+        let controller = MIController::with_transport(transport).unwrap();
+        let mut events = controller.events();
+        let exit = match controller.execute(MiCommand::exec_run()).await {
+            Err(Error::BackendExited(exit)) => exit,
+            other => panic!("unexpected answer {:?}", other),
+        };
+        assert!(exit.status.is_some_and(|s| !s.success()));
+        assert_eq!(exit.stderr, ["boom"]);
+        assert_eq!(events.recv().await.unwrap(), Event::Exited(exit.clone()));
+        assert_eq!(controller.exit_status(), Some(exit));
+        assert!(matches!(
+            controller.execute(MiCommand::exec_run()).await,
+            Err(Error::BackendExited(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_hang_up() {
+        let (fake, controller) = FakeGdb::controller().unwrap();
+        fake.on("-exec-run", Vec::<String>::new());
+        let hang_up = async {
+            tokio::task::yield_now().await;
+            fake.hang_up().await;
+        };
+        let (outcome, _) = tokio::join!(controller.execute(MiCommand::exec_run()), hang_up);
+        match outcome {
+            Err(Error::BackendExited(exit)) => assert_eq!(exit, BackendExit::default()),
+            other => panic!("unexpected answer {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_state() {
+        let (fake, controller) = FakeGdb::controller().unwrap();
+        let mut events = controller.events();
+        fake.emit("=thread-group-added,id=\"i1\"").await;
+        fake.emit("=thread-created,id=\"1\",group-id=\"i1\"").await;
+        fake.emit("*stopped,thread-id=\"1\",stopped-threads=\"all\"")
+            .await;
+        for _ in 0..3 {
+            events.recv().await.unwrap();
+        }
+        assert!(controller.is_thread_stopped(1));
+        assert!(controller.state().inferior("i1").is_some());
+    }
+
+    async fn start_running(fake: &FakeGdb, controller: &MIController) {
+        let mut events = controller.events();
+        fake.emit("=thread-created,id=\"1\",group-id=\"i1\"").await;
+        fake.emit("*running,thread-id=\"all\"").await;
+        for _ in 0..2 {
+            events.recv().await.unwrap();
+        }
+        assert!(controller.state().any_running());
+    }
+
+    #[tokio::test]
+    async fn test_queue_while_running() {
+        let (fake, controller) = FakeGdb::controller().unwrap();
+        fake.on("-break-insert", ["^done,bkpt={number=\"1\"}"])
+            .on("-thread-info", ["^done,threads=[]"]);
+        start_running(&fake, &controller).await;
+
+        let stop = async {
+            // Safe commands aren't held back
+            controller.execute(MiCommand::thread_info()).await.unwrap();
+            fake.assert_received(&["-thread-info"]);
+            fake.emit("*stopped,thread-id=\"1\",stopped-threads=\"all\"")
+                .await;
+        };
+        let (answer, _) = tokio::join!(controller.execute(MiCommand::break_insert("main")), stop);
+        assert!(answer.is_ok());
+        fake.assert_received(&["-thread-info", "-break-insert main"]);
+    }
+
+    #[tokio::test]
+    async fn test_interrupt_while_running() {
+        let (fake, controller) = FakeGdb::controller().unwrap();
+        fake.on("-break-insert", ["^done,bkpt={number=\"1\"}"])
+            .on(
+                "-exec-interrupt",
+                ["^done", "*stopped,thread-id=\"1\",stopped-threads=\"all\""],
+            )
+            .on("-exec-continue", ["^running", "*running,thread-id=\"all\""])
+            .on("-gdb-set", ["^done"]);
+        controller.set_stop_mode(StopMode::AllStop).await.unwrap();
+        assert!(controller.is_mi_async());
+        start_running(&fake, &controller).await;
+
+        let options = ExecuteOptions::default().while_running(WhileRunning::Interrupt);
+        controller
+            .execute_with(MiCommand::break_insert("main"), options)
+            .await
+            .unwrap();
+        fake.assert_received(&[
+            "-gdb-set mi-async on",
+            "-gdb-set non-stop off",
+            "-exec-interrupt",
+            "-break-insert main",
+            "-exec-continue",
+        ]);
+    }
+
+    #[tokio::test]
+    async fn test_fail_while_running() {
+        let (fake, controller) = FakeGdb::controller().unwrap();
+        start_running(&fake, &controller).await;
+
+        let options = ExecuteOptions::default().while_running(WhileRunning::Fail);
+        assert!(matches!(
+            controller
+                .execute_with(MiCommand::exec_next(), options)
+                .await,
+            Err(Error::Running)
+        ));
+        fake.assert_received(&[]);
+    }
+
+    #[tokio::test]
+    async fn test_interrupt_needs_mi_async() {
+        let (fake, controller) = FakeGdb::controller().unwrap();
+        fake.on("-break-insert", ["^done,bkpt={number=\"1\"}"]);
+        assert!(!controller.is_mi_async());
+        start_running(&fake, &controller).await;
+
+        // GDB wouldn't read an interrupt, the command waits for the stop
+        let options = ExecuteOptions::default().while_running(WhileRunning::Interrupt);
+        let stop = async {
+            tokio::task::yield_now().await;
+            fake.assert_received(&[]);
+            fake.emit("*stopped,thread-id=\"1\",stopped-threads=\"all\"")
+                .await;
+        };
+        let (answer, _) = tokio::join!(
+            controller.execute_with(MiCommand::break_insert("main"), options),
+            stop
+        );
+        assert!(answer.is_ok());
+        fake.assert_received(&["-break-insert main"]);
+    }
+
+    #[tokio::test]
+    async fn test_non_stop() {
+        let (fake, controller) = FakeGdb::controller().unwrap();
+        fake.on("-gdb-set", ["^done"])
+            .on("-stack-list-frames", ["^done,stack=[]"]);
+        controller.set_stop_mode(StopMode::NonStop).await.unwrap();
+        assert_eq!(controller.stop_mode(), StopMode::NonStop);
+
+        let mut events = controller.events();
+        fake.emit("=thread-created,id=\"1\",group-id=\"i1\"").await;
+        fake.emit("=thread-created,id=\"2\",group-id=\"i1\"").await;
+        fake.emit("*stopped,thread-id=\"2\",stopped-threads=[\"2\"]")
+            .await;
+        for _ in 0..3 {
+            events.recv().await.unwrap();
+        }
+
+        // Thread 2 is stopped, thread 1 running doesn't get in the way
+        controller
+            .execute(MiCommand::stack_list_frames().thread(2))
+            .await
+            .unwrap();
+        let stop = async {
+            tokio::task::yield_now().await;
+            fake.assert_received_command("-stack-list-frames --thread 2");
+            fake.emit("*stopped,thread-id=\"1\",stopped-threads=[\"1\"]")
+                .await;
+        };
+        let (answer, _) = tokio::join!(
+            controller.execute(MiCommand::stack_list_frames().thread(1)),
+            stop
+        );
+        assert!(answer.is_ok());
+        fake.assert_received(&[
+            "-gdb-set mi-async on",
+            "-gdb-set non-stop on",
+            "-stack-list-frames --thread 2",
+            "-stack-list-frames --thread 1",
+        ]);
+    }
+
+    #[tokio::test]
+    async fn test_inferior_tty() {
+        let (fake, controller) = FakeGdb::controller().unwrap();
+        fake.on("-inferior-tty-set", ["^done"]);
+        let pty = controller.open_inferior_tty().await.unwrap();
+        let expected = format!("-inferior-tty-set {}", pty.path().display());
+        fake.assert_received(&[expected.as_str()]);
+    }
+
+    #[tokio::test]
+    async fn test_cli() {
+        let (fake, controller) = FakeGdb::controller().unwrap();
+        // More output than a subscriber could keep up with
+        let mut lines: Vec<_> = (0..EVENTS_CAPACITY * 2)
+            .map(|i| format!("~\"line {}\\n\"", i))
+            .collect();
+        lines.push("^done".to_owned());
+        fake.on(
+            "-interpreter-exec",
+            ["~\"rip 0x401000\\n\"", "~\"rsp 0x7ffe\\n\"", "^done"],
+        )
+        .on("-interpreter-exec console disassemble", lines);
+        let output = controller.cli("info registers").await.unwrap();
+        assert_eq!(output, "rip 0x401000\nrsp 0x7ffe\n");
+        fake.assert_received(&["-interpreter-exec console \"info registers\""]);
+
+        let output = controller.cli("disassemble").await.unwrap();
+        assert_eq!(output.lines().count(), EVENTS_CAPACITY * 2);
+        assert!(output.ends_with(&format!("line {}\n", EVENTS_CAPACITY * 2 - 1)));
+    }
+
+    #[tokio::test]
+    async fn test_connect_remote() {
+        let (fake, controller) = FakeGdb::controller().unwrap();
+        fake.on("-gdb-set", ["^done"])
+            .on("-target-select", ["^connected"])
+            .on("-target-file-get", ["^done"]);
+        let target = Target::Remote("127.0.0.1:2345".to_owned());
+        controller
+            .connect_remote(&target, Some("target:"))
+            .await
+            .unwrap();
+        controller
+            .get_file("/var/log/app.log", "app.log")
+            .await
+            .unwrap();
+        fake.assert_received(&[
+            "-gdb-set sysroot target:",
+            "-target-select remote 127.0.0.1:2345",
+            "-target-file-get /var/log/app.log app.log",
+        ]);
+    }
+
+    #[tokio::test]
+    async fn test_load_core() {
+        let (fake, controller) = FakeGdb::controller().unwrap();
+        fake.on("-file-exec-and-symbols", ["^done"]).on(
+            "-target-select core",
+            [
+                "=thread-group-started,id=\"i1\",pid=\"4242\"",
+                "=thread-created,id=\"1\",group-id=\"i1\"",
+                "~\"Core was generated by `./app'.\\n\"",
+                "~\"Program terminated with signal SIGSEGV, Segmentation fault.\\n\"",
+                "*stopped,frame={addr=\"0x401136\",func=\"main\",args=[]},thread-id=\"1\",\
+                 stopped-threads=\"all\"",
+                "^connected",
+            ],
+        );
+        assert!(!controller.is_post_mortem());
+        let dump = controller.load_core("./app", "core.4242").await.unwrap();
+        assert_eq!(
+            dump,
+            CoreDump {
+                signal: Some("SIGSEGV".to_owned()),
+                description: Some("Segmentation fault".to_owned()),
+                thread: Some(1),
+            }
+        );
+        assert!(controller.is_post_mortem());
+        assert!(controller.is_thread_stopped(1));
+        assert!(matches!(
+            controller.execute(MiCommand::exec_continue()).await,
+            Err(Error::PostMortem)
+        ));
+        fake.assert_received(&[
+            "-file-exec-and-symbols ./app",
+            "-target-select core core.4242",
+        ]);
+    }
+
+    #[tokio::test]
+    async fn test_load_core_without_stopped() {
+        let (fake, controller) = FakeGdb::controller().unwrap();
+        fake.on("-file-exec-and-symbols", ["^done"])
+            .on(
+                "-target-select core",
+                ["=thread-created,id=\"1\",group-id=\"i1\"", "^connected"],
+            )
+            .on("-stack-list-frames", ["^done,stack=[]"]);
+        controller.load_core("./app", "core.4242").await.unwrap();
+        assert!(controller.is_thread_stopped(1));
+        // Not held back waiting for a stop that never comes
+        let frames = tokio::time::timeout(
+            Duration::from_secs(5),
+            controller.execute(MiCommand::stack_list_frames()),
+        );
+        frames.await.unwrap().unwrap();
+    }
+}
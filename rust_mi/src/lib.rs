@@ -1,21 +1,18 @@
+pub mod blocking;
 pub mod commands;
+pub mod config;
+mod controller;
 pub mod parser;
+#[cfg(unix)]
+pub mod pty;
+pub mod state;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+pub mod transcript;
+pub mod transport;
 pub mod types;
 
-#[derive(Debug)]
-pub struct MIController {}
-
-impl Default for MIController {
-    fn default() -> Self {
-        Self {}
-    }
-}
-
-impl MIController {
-    pub fn new() -> MIController {
-        todo!()
-    }
-}
+pub use controller::{CancelToken, Event, ExecuteOptions, MIController, StopMode, WhileRunning};
 
 #[cfg(test)]
 mod tests {
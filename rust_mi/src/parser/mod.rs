@@ -2,11 +2,11 @@ use std::borrow::Cow;
 
 use nom::{branch::alt,
           bytes::complete::tag,
-          character::complete::{alpha1, alphanumeric1, crlf, digit0, multispace0},
-          combinator::{map, peek, recognize},
+          character::complete::{alpha1, alphanumeric1, digit0, line_ending, multispace0},
+          combinator::{all_consuming, map, opt, peek, recognize},
           error::{context, VerboseError},
           multi::{many0, separated_list0, separated_list1},
-          sequence::{delimited, pair, separated_pair, tuple},
+          sequence::{delimited, pair, preceded, separated_pair, terminated, tuple},
           IResult as NomResult};
 
 pub mod output_types;
@@ -18,65 +18,95 @@ type IResult<T, U> = NomResult<T, U, VerboseError<T>>;
 
 use self::strings::parse_string;
 
-pub fn parse_mi_output(input: &str) -> IResult<&str, Output> {
-    todo!()
+/// Parses a single line of GDB/MI output, with or without its line terminator.
+pub fn parse_mi_output(input: &str) -> IResult<&str, Output<'_>> {
+    let result = map(result_record, Output::ResultRecord);
+    let oob = map(oob_record, Output::OOBRecord);
+    let term = map(termination, |_| Output::Terminator);
+    context(
+        "mi_output",
+        all_consuming(terminated(alt((result, oob, term)), opt(line_ending))),
+    )(input)
 }
 
-fn result_record(input: &str) -> IResult<&str, AsyncOutput> {
-    todo!()
+fn result_record(input: &str) -> IResult<&str, OutputData<'_>> {
+    let parser = context(
+        "result_record",
+        tuple((token, tag("^"), result_class, results)),
+    );
+    map(parser, |x| OutputData(x.0, x.2, x.3))(input)
 }
 
-fn oob_record(input: &str) -> IResult<&str, OOB> {
-    let ar = map(async_record, |x| OOB::AsyncRecord(x));
-    let sr = map(stream_record, |x| OOB::StreamRecord(x));
+fn oob_record(input: &str) -> IResult<&str, OOB<'_>> {
+    let ar = map(async_record, OOB::AsyncRecord);
+    let sr = map(stream_record, OOB::StreamRecord);
     context("oob_record", alt((ar, sr)))(input)
 }
 
-fn stream_record(input: &str) -> IResult<&str, StreamOutput> {
-    todo!()
+fn stream_record(input: &str) -> IResult<&str, StreamOutput<'_>> {
+    let console = map(preceded(tag("~"), parse_string), |s| {
+        StreamOutput::Console(Cow::from(s))
+    });
+    let target = map(preceded(tag("@"), parse_string), |s| {
+        StreamOutput::Target(Cow::from(s))
+    });
+    let log = map(preceded(tag("&"), parse_string), |s| {
+        StreamOutput::Log(Cow::from(s))
+    });
+    context("stream_record", alt((console, target, log)))(input)
 }
 
-fn async_record(input: &str) -> IResult<&str, AsyncOutput> {
-    todo!()
+fn async_record(input: &str) -> IResult<&str, AsyncOutput<'_>> {
+    context(
+        "async_record",
+        alt((exec_async_record, status_async_record, notify_async_record)),
+    )(input)
 }
 
 fn async_record_kind<'a, F>(
     input: &'a str,
     ctx: &'static str,
+    marker: &'static str,
     f: F,
 ) -> IResult<&'a str, AsyncOutput<'a>>
 where
-    F: Fn((Option<Token>, OutputClass, Vec<Variable>)) -> AsyncOutput,
+    F: Fn((Option<Token>, OutputClass, Vec<Variable<'a>>)) -> AsyncOutput<'a>,
 {
-    let parser = context(ctx, tuple((token, tag("*"), async_output, crlf)));
+    let parser = context(ctx, tuple((token, tag(marker), async_output)));
     map(parser, move |x| f((x.0, x.2 .0, x.2 .1)))(input)
 }
 
-fn exec_async_record(input: &str) -> IResult<&str, AsyncOutput> {
-    async_record_kind(input, "exec_async", |x| {
+fn exec_async_record(input: &str) -> IResult<&str, AsyncOutput<'_>> {
+    async_record_kind(input, "exec_async", "*", |x| {
         AsyncOutput::ExeAsync(OutputData(x.0, x.1, x.2))
     })
 }
 
-fn status_async_record(input: &str) -> IResult<&str, AsyncOutput> {
-    async_record_kind(input, "status_async", |x| {
+fn status_async_record(input: &str) -> IResult<&str, AsyncOutput<'_>> {
+    async_record_kind(input, "status_async", "+", |x| {
         AsyncOutput::StatusAsync(OutputData(x.0, x.1, x.2))
     })
 }
 
-fn notify_async_record(input: &str) -> IResult<&str, AsyncOutput> {
-    async_record_kind(input, "notify_async", |x| {
+fn notify_async_record(input: &str) -> IResult<&str, AsyncOutput<'_>> {
+    async_record_kind(input, "notify_async", "=", |x| {
         AsyncOutput::NotifyAsync(OutputData(x.0, x.1, x.2))
     })
 }
 
-fn async_output(input: &str) -> IResult<&str, (OutputClass, Vec<Variable>)> {
-    let parser = tuple((async_class, tag(","), result_list));
-    map(parser, |v| (v.0, v.2))(input)
+fn async_output(input: &str) -> IResult<&str, (OutputClass, Vec<Variable<'_>>)> {
+    pair(async_class, results)(input)
 }
 
-// TODO: It's let's wasteful to use Option instead of an empty Vec
-fn result_list(input: &str) -> IResult<&str, Vec<Variable>> {
+// Optional `,result` tail shared by result and async records
+fn results(input: &str) -> IResult<&str, Vec<Variable<'_>>> {
+    map(
+        opt(preceded(tag(","), result_list)),
+        Option::unwrap_or_default,
+    )(input)
+}
+
+fn result_list(input: &str) -> IResult<&str, Vec<Variable<'_>>> {
     context("result_list", separated_list1(tag(","), variable))(input)
 }
 
@@ -90,9 +120,7 @@ fn result_class(input: &str) -> IResult<&str, OutputClass> {
 }
 
 fn async_class(input: &str) -> IResult<&str, OutputClass> {
-    let stopped = map(tag("stopped"), |_| OutputClass::Stopped);
-    let unknown = map(alpha1, |_| OutputClass::Unknown);
-    alt((stopped, unknown))(input)
+    context("async_class", map(identifier, OutputClass::from))(input)
 }
 
 fn token(input: &str) -> IResult<&str, Option<Token>> {
@@ -102,9 +130,9 @@ fn token(input: &str) -> IResult<&str, Option<Token>> {
     })(input)
 }
 
-fn variable(input: &str) -> IResult<&str, Variable> {
+fn variable(input: &str) -> IResult<&str, Variable<'_>> {
     let parser = context("variable", separated_pair(identifier, tag("="), value));
-    map(parser, |v| Variable(v.0, v.1))(input)
+    map(parser, |v| Variable(Cow::from(v.0), v.1))(input)
 }
 
 fn identifier(input: &str) -> IResult<&str, &str> {
@@ -117,39 +145,39 @@ fn identifier(input: &str) -> IResult<&str, &str> {
     )(input)
 }
 
-fn value(input: &str) -> IResult<&str, Value> {
+fn value(input: &str) -> IResult<&str, Value<'_>> {
     context("value", alt((constant, tuple_value, list)))(input)
 }
 
-fn constant(input: &str) -> IResult<&str, Value> {
+fn constant(input: &str) -> IResult<&str, Value<'_>> {
     match context("constant", parse_string)(input) {
         Ok((rest, x)) => Ok((rest, Value::Const(Cow::from(x)))),
         Err(x) => Err(x),
     }
 }
 
-fn tuple_value(input: &str) -> IResult<&str, Value> {
+fn tuple_value(input: &str) -> IResult<&str, Value<'_>> {
     let parser = context(
         "tuple_value",
         delimited(tag("{"), separated_list0(tag(","), variable), tag("}")),
     );
-    match map(parser, |v| TupleValue::from(v))(input) {
+    match map(parser, TupleValue::from)(input) {
         Ok((r, t)) => Ok((r, Value::Tuple(t))),
         Err(x) => Err(x),
     }
 }
 
-fn variable_list(input: &str) -> IResult<&str, ListValue> {
+fn variable_list(input: &str) -> IResult<&str, ListValue<'_>> {
     context(
         "variable_list",
-        map(separated_list1(tag(","), variable), |v| ListValue::from(v)),
+        map(separated_list1(tag(","), variable), ListValue::from),
     )(input)
 }
 
-fn value_list(input: &str) -> IResult<&str, ListValue> {
+fn value_list(input: &str) -> IResult<&str, ListValue<'_>> {
     context(
         "value_list",
-        map(separated_list1(tag(","), value), |v| ListValue::from(v)),
+        map(separated_list1(tag(","), value), ListValue::from),
     )(input)
 }
 
@@ -157,20 +185,20 @@ fn empty<T: Default>(input: &str) -> IResult<&str, T> {
     context("context", map(ws(peek(tag("]"))), |_| T::default()))(input)
 }
 
-fn list(input: &str) -> IResult<&str, Value> {
+fn list(input: &str) -> IResult<&str, Value<'_>> {
     let variable_or_value = alt((variable_list, value_list, empty));
     let parser = context("list", delimited(tag("["), variable_or_value, tag("]")));
-    map(parser, |v| Value::List(v))(input)
+    map(parser, Value::List)(input)
 }
 
 fn termination(input: &str) -> IResult<&str, ()> {
-    map(tag("(gdb)"), |_| ())(input)
+    map(terminated(tag("(gdb)"), multispace0), |_| ())(input)
 }
 
 // Trim whitespace
-fn ws<'a, F: 'a, O>(inner: F) -> impl FnMut(&'a str) -> IResult<&'a str, O>
+fn ws<'a, F, O>(inner: F) -> impl FnMut(&'a str) -> IResult<&'a str, O>
 where
-    F: FnMut(&'a str) -> IResult<&'a str, O>,
+    F: FnMut(&'a str) -> IResult<&'a str, O> + 'a,
 {
     delimited(multispace0, inner, multispace0)
 }
@@ -216,15 +244,58 @@ mod tests {
         };
     }
 
+    fn c(s: &str) -> Value<'_> {
+        Value::Const(Cow::from(s))
+    }
+
+    fn v<'a>(name: &'a str, value: Value<'a>) -> Variable<'a> {
+        Variable(Cow::from(name), value)
+    }
+
+    fn frame() -> Variable<'static> {
+        v(
+            "frame",
+            Value::Tuple(TupleValue::Data(vec![
+                v("addr", c("0x08048564")),
+                v("func", c("main")),
+                v(
+                    "args",
+                    Value::List(ListValue::ValueList(vec![
+                        Value::Tuple(TupleValue::Data(vec![
+                            v("name", c("argc")),
+                            v("value", c("1")),
+                        ])),
+                        Value::Tuple(TupleValue::Data(vec![
+                            v("name", c("argv")),
+                            v("value", c("0xbfc4d4d4")),
+                        ])),
+                    ])),
+                ),
+                v("file", c("myprog.c")),
+                v("fullname", c("/home/nickrob/myprog.c")),
+                v("line", c("68")),
+                v("arch", c("i386:x86_64")),
+            ])),
+        )
+    }
+
     #[test]
     fn test_async_output() {
         let data = "stopped,reason=\"breakpoint-hit\",disp=\"keep\",bkptno=\"1\",thread-id=\"0\",\
                     frame={addr=\"0x08048564\",func=\"main\",args=[{name=\"argc\",value=\"1\"},\
                     {name=\"argv\",value=\"0xbfc4d4d4\"}],file=\"myprog.c\",fullname=\"/home/\
                     nickrob/myprog.c\",line=\"68\",arch=\"i386:x86_64\"}";
-        assert_eq!(
-            async_output(data).unwrap(),
-            ("", (OutputClass::Stopped, Vec::new()))
+        let result = vec![
+            v("reason", c("breakpoint-hit")),
+            v("disp", c("keep")),
+            v("bkptno", c("1")),
+            v("thread-id", c("0")),
+            frame(),
+        ];
+        do_test_result!(
+            data,
+            async_output(data),
+            ("", (OutputClass::Stopped, result))
         )
     }
 
@@ -233,7 +304,58 @@ mod tests {
         let data = "frame={addr=\"0x08048564\",func=\"main\",args=[{name=\"argc\",value=\"1\"},\
                     {name=\"argv\",value=\"0xbfc4d4d4\"}],file=\"myprog.c\",fullname=\"/home/\
                     nickrob/myprog.c\",line=\"68\",arch=\"i386:x86_64\"},data=\"1\",";
-        do_test_result!(data, result_list(data))
+        let result = vec![frame(), v("data", c("1"))];
+        do_test_result!(data, result_list(data), (",", result))
+    }
+
+    #[test]
+    fn test_result_record() {
+        let data = "12^done,bkpt={number=\"1\"}";
+        let result = OutputData(
+            Some(Token(12)),
+            OutputClass::Done,
+            vec![v(
+                "bkpt",
+                Value::Tuple(TupleValue::Data(vec![v("number", c("1"))])),
+            )],
+        );
+        do_test_result!(data, result_record(data), ("", result));
+        let data = "^error,msg=\"No symbol table is loaded.\"";
+        let result = OutputData(
+            None,
+            OutputClass::Error,
+            vec![v("msg", c("No symbol table is loaded."))],
+        );
+        do_test_result!(data, result_record(data), ("", result))
+    }
+
+    #[test]
+    fn test_parse_mi_output() {
+        let data = "*running,thread-id=\"all\"\n";
+        let result = Output::OOBRecord(OOB::AsyncRecord(AsyncOutput::ExeAsync(OutputData(
+            None,
+            OutputClass::Running,
+            vec![v("thread-id", c("all"))],
+        ))));
+        do_test_result!(data, parse_mi_output(data), ("", result));
+        let data = "=thread-group-added,id=\"i1\"";
+        let result = Output::OOBRecord(OOB::AsyncRecord(AsyncOutput::NotifyAsync(OutputData(
+            None,
+            OutputClass::ThreadGroupAdded,
+            vec![v("id", c("i1"))],
+        ))));
+        do_test_result!(data, parse_mi_output(data), ("", result));
+        let data = "~\"GNU gdb (GDB) 12.1\\n\"\r\n";
+        let result = Output::OOBRecord(OOB::StreamRecord(StreamOutput::Console(Cow::from(
+            "GNU gdb (GDB) 12.1\n",
+        ))));
+        do_test_result!(data, parse_mi_output(data), ("", result));
+        let data = "3^running";
+        let result = Output::ResultRecord(OutputData(Some(Token(3)), OutputClass::Running, vec![]));
+        do_test_result!(data, parse_mi_output(data), ("", result));
+        let data = "(gdb) \n";
+        do_test_result!(data, parse_mi_output(data), ("", Output::Terminator));
+        assert!(parse_mi_output("garbage").is_err());
     }
 
     #[test]
@@ -267,12 +389,12 @@ mod tests {
         let data = "[{name=\"argc\",value=\"1\"},{name=\"argv\",value=\"0xbfc4d4d4\"}]";
         let result = Value::List(ListValue::ValueList(vec![
             Value::Tuple(TupleValue::Data(vec![
-                Variable("name", Value::Const(Cow::from("argc"))),
-                Variable("value", Value::Const(Cow::from("1"))),
+                Variable(Cow::from("name"), Value::Const(Cow::from("argc"))),
+                Variable(Cow::from("value"), Value::Const(Cow::from("1"))),
             ])),
             Value::Tuple(TupleValue::Data(vec![
-                Variable("name", Value::Const(Cow::from("argv"))),
-                Variable("value", Value::Const(Cow::from("0xbfc4d4d4"))),
+                Variable(Cow::from("name"), Value::Const(Cow::from("argv"))),
+                Variable(Cow::from("value"), Value::Const(Cow::from("0xbfc4d4d4"))),
             ])),
         ]));
         do_test_result!(data, list(data), ("", result))
@@ -282,7 +404,7 @@ mod tests {
     fn test_list_variables() {
         let data = "[type=\"breakpoint\"]";
         let result = Value::List(ListValue::VariableList(vec![Variable(
-            "type",
+            Cow::from("type"),
             Value::Const(Cow::from("breakpoint")),
         )]));
         do_test_result!(data, list(data), ("", result))
@@ -292,7 +414,7 @@ mod tests {
     fn test_tuple() {
         let data = "{type=\"breakpoint\"}";
         let result = Value::Tuple(TupleValue::Data(vec![Variable(
-            "type",
+            Cow::from("type"),
             Value::Const(Cow::from("breakpoint")),
         )]));
         do_test_result!(data, tuple_value(data), ("", result))
@@ -311,30 +433,30 @@ mod tests {
         let data = "{name=\"argc\",value=\"1\"},{name=\"argv\",value=\"0xbfc4d4d4\"}";
         let result = ListValue::ValueList(vec![
             Value::Tuple(TupleValue::Data(vec![
-                Variable("name", Value::Const(Cow::from("argc"))),
-                Variable("value", Value::Const(Cow::from("1"))),
+                Variable(Cow::from("name"), Value::Const(Cow::from("argc"))),
+                Variable(Cow::from("value"), Value::Const(Cow::from("1"))),
             ])),
             Value::Tuple(TupleValue::Data(vec![
-                Variable("name", Value::Const(Cow::from("argv"))),
-                Variable("value", Value::Const(Cow::from("0xbfc4d4d4"))),
+                Variable(Cow::from("name"), Value::Const(Cow::from("argv"))),
+                Variable(Cow::from("value"), Value::Const(Cow::from("0xbfc4d4d4"))),
             ])),
         ]);
-        do_test_result!(data, variable_list(data), ("", result))
+        do_test_result!(data, value_list(data), ("", result))
     }
 
     #[test]
     fn test_tuple_variable() {
         let data = "args=[{name=\"argc\",value=\"1\"},{name=\"argv\",value=\"0xbfc4d4d4\"}]";
         let result = Variable(
-            "args",
+            Cow::from("args"),
             Value::List(ListValue::ValueList(vec![
                 Value::Tuple(TupleValue::Data(vec![
-                    Variable("name", Value::Const(Cow::from("argc"))),
-                    Variable("value", Value::Const(Cow::from("1"))),
+                    Variable(Cow::from("name"), Value::Const(Cow::from("argc"))),
+                    Variable(Cow::from("value"), Value::Const(Cow::from("1"))),
                 ])),
                 Value::Tuple(TupleValue::Data(vec![
-                    Variable("name", Value::Const(Cow::from("argv"))),
-                    Variable("value", Value::Const(Cow::from("0xbfc4d4d4"))),
+                    Variable(Cow::from("name"), Value::Const(Cow::from("argv"))),
+                    Variable(Cow::from("value"), Value::Const(Cow::from("0xbfc4d4d4"))),
                 ])),
             ])),
         );
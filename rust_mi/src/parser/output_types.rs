@@ -1,47 +1,46 @@
 use std::borrow::Cow;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Output<'a> {
     ResultRecord(OutputData<'a>),
     OOBRecord(OOB<'a>),
+    Terminator,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum OOB<'a> {
     StreamRecord(StreamOutput<'a>),
     AsyncRecord(AsyncOutput<'a>),
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum AsyncOutput<'a> {
     ExeAsync(OutputData<'a>),
     StatusAsync(OutputData<'a>),
     NotifyAsync(OutputData<'a>),
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct OutputData<'a>(pub Option<Token>, pub OutputClass, pub Vec<Variable<'a>>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum StreamOutput<'a> {
-    Console(&'a str),
-    Target(&'a str),
-    Log(&'a str),
+    Console(Cow<'a, str>),
+    Target(Cow<'a, str>),
+    Log(Cow<'a, str>),
 }
 
-#[derive(Debug, PartialEq)]
-pub struct Variable<'a>(pub &'a str, pub Value<'a>);
-
+#[derive(Debug, Clone, PartialEq)]
+pub struct Variable<'a>(pub Cow<'a, str>, pub Value<'a>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Value<'a> {
     Const(Cow<'a, str>),
     Tuple(TupleValue<'a>),
     List(ListValue<'a>),
 }
 
-
-impl<'a>  From<&'a str> for Value<'a> {
+impl<'a> From<&'a str> for Value<'a> {
     fn from(f: &'a str) -> Self {
         Value::Const(Cow::from(f))
     }
@@ -73,27 +72,17 @@ impl<'a> From<Vec<Value<'a>>> for ListValue<'a> {
     }
 }
 
-impl<'a> Default for ListValue<'a> {
-    fn default() -> Self {
-        ListValue::Empty
-    }
-}
-
-impl<'a> Default for TupleValue<'a> {
-    fn default() -> Self {
-        TupleValue::Empty
-    }
-}
-
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub enum ListValue<'a> {
+    #[default]
     Empty,
     ValueList(Vec<Value<'a>>),
     VariableList(Vec<Variable<'a>>),
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub enum TupleValue<'a> {
+    #[default]
     Empty,
     Data(Vec<Variable<'a>>),
 }
@@ -105,7 +94,7 @@ pub enum StreamKind<'a> {
     Log(&'a str),
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OutputClass {
     Done,
     Running,
@@ -113,8 +102,183 @@ pub enum OutputClass {
     Error,
     Exit,
     Stopped,
+    ThreadGroupAdded,
+    ThreadGroupRemoved,
+    ThreadGroupStarted,
+    ThreadGroupExited,
+    ThreadCreated,
+    ThreadExited,
+    ThreadSelected,
+    LibraryLoaded,
+    LibraryUnloaded,
+    BreakpointCreated,
+    BreakpointModified,
+    BreakpointDeleted,
     Unknown,
 }
 
-#[derive(Debug, PartialEq)]
+impl From<&str> for OutputClass {
+    fn from(class: &str) -> Self {
+        match class {
+            "done" => OutputClass::Done,
+            "running" => OutputClass::Running,
+            "connected" => OutputClass::Connected,
+            "error" => OutputClass::Error,
+            "exit" => OutputClass::Exit,
+            "stopped" => OutputClass::Stopped,
+            "thread-group-added" => OutputClass::ThreadGroupAdded,
+            "thread-group-removed" => OutputClass::ThreadGroupRemoved,
+            "thread-group-started" => OutputClass::ThreadGroupStarted,
+            "thread-group-exited" => OutputClass::ThreadGroupExited,
+            "thread-created" => OutputClass::ThreadCreated,
+            "thread-exited" => OutputClass::ThreadExited,
+            "thread-selected" => OutputClass::ThreadSelected,
+            "library-loaded" => OutputClass::LibraryLoaded,
+            "library-unloaded" => OutputClass::LibraryUnloaded,
+            "breakpoint-created" => OutputClass::BreakpointCreated,
+            "breakpoint-modified" => OutputClass::BreakpointModified,
+            "breakpoint-deleted" => OutputClass::BreakpointDeleted,
+            _ => OutputClass::Unknown,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Token(pub u32);
+
+// The parser borrows from the line it was given, records that need to outlive
+// that line (i.e. anything crossing a task boundary) are converted with
+// `into_owned`.
+
+impl<'a> Output<'a> {
+    pub fn into_owned(self) -> Output<'static> {
+        match self {
+            Output::ResultRecord(d) => Output::ResultRecord(d.into_owned()),
+            Output::OOBRecord(r) => Output::OOBRecord(r.into_owned()),
+            Output::Terminator => Output::Terminator,
+        }
+    }
+}
+
+impl<'a> OOB<'a> {
+    pub fn into_owned(self) -> OOB<'static> {
+        match self {
+            OOB::StreamRecord(s) => OOB::StreamRecord(s.into_owned()),
+            OOB::AsyncRecord(a) => OOB::AsyncRecord(a.into_owned()),
+        }
+    }
+}
+
+impl<'a> AsyncOutput<'a> {
+    pub fn into_owned(self) -> AsyncOutput<'static> {
+        match self {
+            AsyncOutput::ExeAsync(d) => AsyncOutput::ExeAsync(d.into_owned()),
+            AsyncOutput::StatusAsync(d) => AsyncOutput::StatusAsync(d.into_owned()),
+            AsyncOutput::NotifyAsync(d) => AsyncOutput::NotifyAsync(d.into_owned()),
+        }
+    }
+
+    pub fn data(&self) -> &OutputData<'a> {
+        match self {
+            AsyncOutput::ExeAsync(d)
+            | AsyncOutput::StatusAsync(d)
+            | AsyncOutput::NotifyAsync(d) => d,
+        }
+    }
+}
+
+impl<'a> StreamOutput<'a> {
+    pub fn into_owned(self) -> StreamOutput<'static> {
+        match self {
+            StreamOutput::Console(s) => StreamOutput::Console(Cow::Owned(s.into_owned())),
+            StreamOutput::Target(s) => StreamOutput::Target(Cow::Owned(s.into_owned())),
+            StreamOutput::Log(s) => StreamOutput::Log(Cow::Owned(s.into_owned())),
+        }
+    }
+}
+
+impl<'a> OutputData<'a> {
+    pub fn into_owned(self) -> OutputData<'static> {
+        OutputData(
+            self.0,
+            self.1,
+            self.2.into_iter().map(Variable::into_owned).collect(),
+        )
+    }
+
+    /// Looks up a top level result by name.
+    pub fn get(&self, name: &str) -> Option<&Value<'a>> {
+        self.2.iter().find(|v| v.0 == name).map(|v| &v.1)
+    }
+
+    /// Looks up a top level result by name, only if it's a constant.
+    pub fn get_str(&self, name: &str) -> Option<&str> {
+        self.get(name).and_then(Value::as_str)
+    }
+}
+
+impl<'a> Variable<'a> {
+    pub fn into_owned(self) -> Variable<'static> {
+        Variable(Cow::Owned(self.0.into_owned()), self.1.into_owned())
+    }
+}
+
+impl<'a> Value<'a> {
+    pub fn into_owned(self) -> Value<'static> {
+        match self {
+            Value::Const(c) => Value::Const(Cow::Owned(c.into_owned())),
+            Value::Tuple(t) => Value::Tuple(t.into_owned()),
+            Value::List(l) => Value::List(l.into_owned()),
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::Const(c) => Some(c),
+            _ => None,
+        }
+    }
+
+    /// The items of a list, `name=value` items included, nothing for
+    /// anything else.
+    pub fn values(&self) -> Vec<&Value<'a>> {
+        match self {
+            Value::List(ListValue::ValueList(values)) => values.iter().collect(),
+            Value::List(ListValue::VariableList(vars)) => vars.iter().map(|v| &v.1).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Looks up a field by name when the value is a tuple.
+    pub fn get(&self, name: &str) -> Option<&Value<'a>> {
+        match self {
+            Value::Tuple(TupleValue::Data(vars)) => vars.iter().find(|v| v.0 == name).map(|v| &v.1),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> TupleValue<'a> {
+    pub fn into_owned(self) -> TupleValue<'static> {
+        match self {
+            TupleValue::Empty => TupleValue::Empty,
+            TupleValue::Data(v) => {
+                TupleValue::Data(v.into_iter().map(Variable::into_owned).collect())
+            }
+        }
+    }
+}
+
+impl<'a> ListValue<'a> {
+    pub fn into_owned(self) -> ListValue<'static> {
+        match self {
+            ListValue::Empty => ListValue::Empty,
+            ListValue::ValueList(v) => {
+                ListValue::ValueList(v.into_iter().map(Value::into_owned).collect())
+            }
+            ListValue::VariableList(v) => {
+                ListValue::VariableList(v.into_iter().map(Variable::into_owned).collect())
+            }
+        }
+    }
+}
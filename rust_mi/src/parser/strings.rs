@@ -1,7 +1,6 @@
 use nom::{branch::alt,
           bytes::streaming::{is_not, take_while_m_n},
-          character::{complete::multispace0,
-                      streaming::{char, multispace1}},
+          character::streaming::{char, multispace1},
           combinator::{map, map_opt, map_res, value, verify},
           error::{FromExternalError, ParseError},
           multi::fold_many0,
@@ -42,7 +41,7 @@ where
     // the function returns None, map_opt returns an error. In this case, because
     // not all u32 values are valid unicode code points, we have to fallibly
     // convert to char with from_u32.
-    map_opt(parse_u32, |value| std::char::from_u32(value))(input)
+    map_opt(parse_u32, std::char::from_u32)(input)
 }
 
 /// Parse an escaped character: \n, \t, \r, \u{00AC}, etc.
@@ -0,0 +1,225 @@
+//! Pseudo terminal for the inferior's I/O, so the program's output doesn't
+//! end up mixed with the MI stream.
+
+use std::{ffi::CStr,
+          io,
+          os::unix::{ffi::OsStrExt,
+                     io::{AsRawFd, FromRawFd, OwnedFd}},
+          path::{Path, PathBuf},
+          pin::Pin,
+          sync::Arc,
+          task::{Context, Poll}};
+
+use tokio::io::{unix::AsyncFd, AsyncRead, AsyncWrite, ReadBuf};
+
+/// The master side of a pseudo terminal, the inferior gets the slave side
+/// through its [`path`](Pty::path).
+///
+/// The terminal is put in raw mode so output comes through untouched.
+#[derive(Debug)]
+pub struct Pty {
+    master: Arc<AsyncFd<OwnedFd>>,
+    path: PathBuf,
+    // Kept open so reading the master doesn't fail with EIO between runs
+    _slave: OwnedFd,
+}
+
+impl Pty {
+    /// Allocates a new pseudo terminal, must be called from a tokio runtime.
+    pub fn open() -> io::Result<Pty> {
+        let master = cvt(unsafe { libc::posix_openpt(libc::O_RDWR | libc::O_NOCTTY) })?;
+        let master = unsafe { OwnedFd::from_raw_fd(master) };
+        let fd = master.as_raw_fd();
+        cvt(unsafe { libc::grantpt(fd) })?;
+        cvt(unsafe { libc::unlockpt(fd) })?;
+        let path = slave_path(fd)?;
+
+        let slave = std::ffi::CString::new(path.as_os_str().as_bytes())?;
+        let slave = cvt(unsafe { libc::open(slave.as_ptr(), libc::O_RDWR | libc::O_NOCTTY) })?;
+        let slave = unsafe { OwnedFd::from_raw_fd(slave) };
+        unsafe {
+            let mut termios = std::mem::zeroed::<libc::termios>();
+            cvt(libc::tcgetattr(slave.as_raw_fd(), &mut termios))?;
+            libc::cfmakeraw(&mut termios);
+            cvt(libc::tcsetattr(slave.as_raw_fd(), libc::TCSANOW, &termios))?;
+            let flags = cvt(libc::fcntl(fd, libc::F_GETFL))?;
+            cvt(libc::fcntl(fd, libc::F_SETFL, flags | libc::O_NONBLOCK))?;
+        }
+        // SAFETY: the `OwnedFd` is open and moves into the `AsyncFd`, which
+        // closes it on drop and never hands out anything else
+        let master = unsafe { AsyncFd::register(master)? };
+        Ok(Pty {
+            master: Arc::new(master),
+            path,
+            _slave: slave,
+        })
+    }
+
+    /// Path of the slave side, i.e. `/dev/pts/3`.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Handles to read what the inferior prints and to feed its input. The
+    /// pseudo terminal lives as long as any of them.
+    pub fn split(self) -> (PtyReader, PtyWriter) {
+        let pty = Arc::new(self);
+        (PtyReader(pty.clone()), PtyWriter(pty))
+    }
+
+    fn poll_read(&self, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<io::Result<()>> {
+        loop {
+            let mut guard = match self.master.poll_read_ready(cx) {
+                Poll::Ready(guard) => guard?,
+                Poll::Pending => return Poll::Pending,
+            };
+            let unfilled = buf.initialize_unfilled();
+            let read = guard.try_io(|fd| {
+                cvt_size(unsafe {
+                    libc::read(fd.as_raw_fd(), unfilled.as_mut_ptr().cast(), unfilled.len())
+                })
+            });
+            match read {
+                Ok(Ok(n)) => {
+                    buf.advance(n);
+                    return Poll::Ready(Ok(()));
+                }
+                Ok(Err(e)) => return Poll::Ready(Err(e)),
+                Err(_would_block) => continue,
+            }
+        }
+    }
+
+    fn poll_write(&self, cx: &mut Context<'_>, data: &[u8]) -> Poll<io::Result<usize>> {
+        loop {
+            let mut guard = match self.master.poll_write_ready(cx) {
+                Poll::Ready(guard) => guard?,
+                Poll::Pending => return Poll::Pending,
+            };
+            let written = guard.try_io(|fd| {
+                cvt_size(unsafe { libc::write(fd.as_raw_fd(), data.as_ptr().cast(), data.len()) })
+            });
+            match written {
+                Ok(result) => return Poll::Ready(result),
+                Err(_would_block) => continue,
+            }
+        }
+    }
+}
+
+/// What the inferior writes to its terminal.
+#[derive(Debug)]
+pub struct PtyReader(Arc<Pty>);
+
+/// The inferior's terminal input.
+#[derive(Debug)]
+pub struct PtyWriter(Arc<Pty>);
+
+impl PtyReader {
+    pub fn path(&self) -> &Path {
+        self.0.path()
+    }
+}
+
+impl PtyWriter {
+    pub fn path(&self) -> &Path {
+        self.0.path()
+    }
+}
+
+impl AsyncRead for PtyReader {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        self.0.poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for PtyWriter {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.0.poll_write(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn slave_path(fd: libc::c_int) -> io::Result<PathBuf> {
+    let mut buf = [0 as libc::c_char; 128];
+    let err = unsafe { libc::ptsname_r(fd, buf.as_mut_ptr(), buf.len()) };
+    if err != 0 {
+        return Err(io::Error::from_raw_os_error(err));
+    }
+    let name = unsafe { CStr::from_ptr(buf.as_ptr()) };
+    Ok(PathBuf::from(std::ffi::OsStr::from_bytes(name.to_bytes())))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn slave_path(fd: libc::c_int) -> io::Result<PathBuf> {
+    let name = unsafe { libc::ptsname(fd) };
+    if name.is_null() {
+        return Err(io::Error::last_os_error());
+    }
+    let name = unsafe { CStr::from_ptr(name) };
+    Ok(PathBuf::from(std::ffi::OsStr::from_bytes(name.to_bytes())))
+}
+
+fn cvt(ret: libc::c_int) -> io::Result<libc::c_int> {
+    if ret < 0 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(ret)
+    }
+}
+
+fn cvt_size(ret: libc::ssize_t) -> io::Result<usize> {
+    if ret < 0 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(ret as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs::OpenOptions,
+              io::{Read, Write}};
+
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_pty() {
+        let pty = Pty::open().unwrap();
+        assert!(pty.path().starts_with("/dev"));
+        let mut inferior = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(pty.path())
+            .unwrap();
+        let (mut reader, mut writer) = pty.split();
+
+        inferior.write_all(b"hello\n").unwrap();
+        let mut buf = [0; 6];
+        reader.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf, b"hello\n");
+
+        writer.write_all(b"input").await.unwrap();
+        let mut buf = [0; 5];
+        inferior.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"input");
+    }
+}
@@ -0,0 +1,299 @@
+//! Tracks whether threads and inferiors are running from the async records
+//! GDB emits.
+
+use std::collections::BTreeMap;
+
+use crate::parser::output_types::{AsyncOutput, ListValue, OutputClass, OutputData, Value};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThreadState {
+    Running,
+    Stopped,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThreadInfo {
+    /// GDB's global thread number.
+    pub id: u32,
+    /// The thread group (inferior) the thread belongs to, i.e. `i1`.
+    pub group: Option<String>,
+    pub state: ThreadState,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InferiorState {
+    /// Added but no process started yet.
+    NotStarted,
+    Running,
+    Stopped,
+    /// The process is gone, with its exit code when GDB knows it.
+    Exited(Option<i32>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InferiorInfo {
+    /// Thread group id, i.e. `i1`.
+    pub id: String,
+    pub pid: Option<u32>,
+    started: bool,
+    exit_code: Option<i32>,
+    exited: bool,
+}
+
+/// Execution state of every thread and inferior GDB told us about.
+///
+/// Threads are tracked one by one so the model works both in all-stop mode,
+/// where `*running` and `*stopped` cover every thread, and in non-stop mode,
+/// where they name the threads concerned.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExecutionState {
+    threads: BTreeMap<u32, ThreadInfo>,
+    inferiors: BTreeMap<String, InferiorInfo>,
+}
+
+impl ExecutionState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Applies an async record, anything not about execution is ignored.
+    pub fn update(&mut self, record: &AsyncOutput<'_>) {
+        let data = record.data();
+        match data.1 {
+            OutputClass::Running => self.set_running(data),
+            OutputClass::Stopped => self.set_stopped(data),
+            OutputClass::ThreadGroupAdded => {
+                if let Some(id) = data.get_str("id") {
+                    self.inferiors.insert(
+                        id.to_owned(),
+                        InferiorInfo {
+                            id: id.to_owned(),
+                            pid: None,
+                            started: false,
+                            exit_code: None,
+                            exited: false,
+                        },
+                    );
+                }
+            }
+            OutputClass::ThreadGroupRemoved => {
+                if let Some(id) = data.get_str("id") {
+                    self.inferiors.remove(id);
+                }
+            }
+            OutputClass::ThreadGroupStarted => {
+                if let Some(inferior) = self.inferior_mut(data.get_str("id")) {
+                    inferior.pid = data.get_str("pid").and_then(|p| p.parse().ok());
+                    inferior.started = true;
+                    inferior.exited = false;
+                    inferior.exit_code = None;
+                }
+            }
+            OutputClass::ThreadGroupExited => {
+                let id = data.get_str("id");
+                if let Some(inferior) = self.inferior_mut(id) {
+                    inferior.exit_code = data.get_str("exit-code").and_then(parse_exit_code);
+                    inferior.exited = true;
+                    inferior.pid = None;
+                }
+                // GDB doesn't always announce each thread leaving
+                self.threads.retain(|_, t| t.group.as_deref() != id);
+            }
+            OutputClass::ThreadCreated => {
+                if let Some(id) = data.get_str("id").and_then(|i| i.parse().ok()) {
+                    let group = data.get_str("group-id").map(str::to_owned);
+                    self.threads.insert(
+                        id,
+                        ThreadInfo {
+                            id,
+                            group,
+                            state: ThreadState::Running,
+                        },
+                    );
+                }
+            }
+            OutputClass::ThreadExited => {
+                if let Some(id) = data.get_str("id").and_then(|i| i.parse().ok()) {
+                    self.threads.remove(&id);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    pub fn thread(&self, id: u32) -> Option<&ThreadInfo> {
+        self.threads.get(&id)
+    }
+
+    pub fn threads(&self) -> impl Iterator<Item = &ThreadInfo> {
+        self.threads.values()
+    }
+
+    /// Whether thread `id` is known and stopped, i.e. safe to ask for frames.
+    pub fn is_thread_stopped(&self, id: u32) -> bool {
+        self.thread(id)
+            .is_some_and(|t| t.state == ThreadState::Stopped)
+    }
+
+    /// Whether any thread of any inferior is running.
+    pub fn any_running(&self) -> bool {
+        self.threads().any(|t| t.state == ThreadState::Running)
+    }
+
+    pub fn inferior(&self, id: &str) -> Option<&InferiorInfo> {
+        self.inferiors.get(id)
+    }
+
+    pub fn inferiors(&self) -> impl Iterator<Item = &InferiorInfo> {
+        self.inferiors.values()
+    }
+
+    /// An inferior is running as long as one of its threads is.
+    pub fn inferior_state(&self, id: &str) -> Option<InferiorState> {
+        let inferior = self.inferior(id)?;
+        let state = if inferior.exited {
+            InferiorState::Exited(inferior.exit_code)
+        } else if !inferior.started {
+            InferiorState::NotStarted
+        } else if self
+            .threads()
+            .any(|t| t.group.as_deref() == Some(id) && t.state == ThreadState::Running)
+        {
+            InferiorState::Running
+        } else {
+            InferiorState::Stopped
+        };
+        Some(state)
+    }
+
+    fn inferior_mut(&mut self, id: Option<&str>) -> Option<&mut InferiorInfo> {
+        self.inferiors.get_mut(id?)
+    }
+
+    fn set_running(&mut self, data: &OutputData<'_>) {
+        match data.get_str("thread-id") {
+            Some("all") | None => self.set_all(ThreadState::Running),
+            Some(id) => self.set_one(id, ThreadState::Running),
+        }
+    }
+
+    fn set_stopped(&mut self, data: &OutputData<'_>) {
+        match data.get("stopped-threads") {
+            Some(Value::Const(all)) if all == "all" => self.set_all(ThreadState::Stopped),
+            Some(Value::List(ListValue::ValueList(ids))) => {
+                for id in ids.iter().filter_map(Value::as_str) {
+                    self.set_one(id, ThreadState::Stopped);
+                }
+            }
+            // The process exiting only names the inferior, if anything
+            _ => match data.get_str("thread-id") {
+                Some(id) => self.set_one(id, ThreadState::Stopped),
+                None => self.set_all(ThreadState::Stopped),
+            },
+        }
+    }
+
+    pub(crate) fn set_all(&mut self, state: ThreadState) {
+        for thread in self.threads.values_mut() {
+            thread.state = state;
+        }
+    }
+
+    fn set_one(&mut self, id: &str, state: ThreadState) {
+        let thread = id.parse().ok().and_then(|id| self.threads.get_mut(&id));
+        if let Some(thread) = thread {
+            thread.state = state;
+        }
+    }
+}
+
+// GDB reports exit codes in octal
+fn parse_exit_code(code: &str) -> Option<i32> {
+    i32::from_str_radix(code, 8).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{output_types::{Output, OOB},
+                        parse_mi_output};
+
+    fn apply(state: &mut ExecutionState, lines: &[&str]) {
+        for line in lines {
+            match parse_mi_output(line) {
+                Ok((_, Output::OOBRecord(OOB::AsyncRecord(record)))) => state.update(&record),
+                other => panic!("not an async record {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_all_stop() {
+        let mut state = ExecutionState::new();
+        apply(
+            &mut state,
+            &[
+                "=thread-group-added,id=\"i1\"",
+                "=thread-group-started,id=\"i1\",pid=\"4242\"",
+                "=thread-created,id=\"1\",group-id=\"i1\"",
+                "=thread-created,id=\"2\",group-id=\"i1\"",
+                "*running,thread-id=\"all\"",
+            ],
+        );
+        assert_eq!(state.inferior("i1").unwrap().pid, Some(4242));
+        assert_eq!(state.inferior_state("i1"), Some(InferiorState::Running));
+        assert!(!state.is_thread_stopped(1));
+
+        apply(
+            &mut state,
+            &["*stopped,reason=\"breakpoint-hit\",thread-id=\"2\",stopped-threads=\"all\""],
+        );
+        assert!(state.is_thread_stopped(1));
+        assert!(state.is_thread_stopped(2));
+        assert!(!state.any_running());
+        assert_eq!(state.inferior_state("i1"), Some(InferiorState::Stopped));
+
+        apply(
+            &mut state,
+            &[
+                "=thread-exited,id=\"2\",group-id=\"i1\"",
+                "=thread-group-exited,id=\"i1\",exit-code=\"012\"",
+            ],
+        );
+        assert!(state.thread(1).is_none());
+        assert!(!state.is_thread_stopped(3));
+        assert_eq!(
+            state.inferior_state("i1"),
+            Some(InferiorState::Exited(Some(10)))
+        );
+    }
+
+    #[test]
+    fn test_non_stop() {
+        let mut state = ExecutionState::new();
+        apply(
+            &mut state,
+            &[
+                "=thread-group-added,id=\"i1\"",
+                "=thread-group-started,id=\"i1\",pid=\"1\"",
+                "=thread-created,id=\"1\",group-id=\"i1\"",
+                "=thread-created,id=\"2\",group-id=\"i1\"",
+                "=thread-created,id=\"3\",group-id=\"i1\"",
+                "*stopped,reason=\"signal-received\",thread-id=\"3\",stopped-threads=[\"3\"]",
+            ],
+        );
+        assert!(state.is_thread_stopped(3));
+        assert!(!state.is_thread_stopped(1));
+        assert_eq!(state.inferior_state("i1"), Some(InferiorState::Running));
+
+        apply(
+            &mut state,
+            &["*stopped,thread-id=\"1\",stopped-threads=[\"1\",\"2\"]"],
+        );
+        assert_eq!(state.inferior_state("i1"), Some(InferiorState::Stopped));
+        apply(&mut state, &["*running,thread-id=\"2\""]);
+        assert!(!state.is_thread_stopped(2));
+        assert!(state.is_thread_stopped(1));
+        assert_eq!(state.inferior_state("i2"), None);
+    }
+}
@@ -0,0 +1,209 @@
+//! Scriptable stand in for GDB, so MI flows can be tested without a gdb
+//! binary around.
+
+use std::sync::{Arc, Mutex as StdMutex};
+
+use tokio::{io::{AsyncBufReadExt, AsyncWriteExt, BufReader, DuplexStream, WriteHalf},
+            sync::Mutex,
+            task::JoinHandle};
+
+use crate::{transport::MemoryTransport, types::Result, MIController};
+
+#[derive(Debug)]
+struct Rule {
+    pattern: String,
+    records: Vec<String>,
+}
+
+/// Answers MI commands from a rule table over a [`MemoryTransport`].
+///
+/// Each rule maps a command prefix (token stripped, i.e. `-break-insert`) to
+/// the records sent back. Records starting with `^` get the command token
+/// prepended, everything else is sent verbatim and a `(gdb)` prompt follows.
+/// When several rules match the last one added wins, commands without a rule
+/// get an `^error`.
+#[derive(Debug)]
+pub struct FakeGdb {
+    rules: Arc<StdMutex<Vec<Rule>>>,
+    received: Arc<StdMutex<Vec<String>>>,
+    writer: Arc<Mutex<WriteHalf<DuplexStream>>>,
+    task: JoinHandle<()>,
+}
+
+impl FakeGdb {
+    /// Starts serving, the returned transport is meant for
+    /// [`MIController::with_transport`].
+    pub fn spawn() -> (FakeGdb, MemoryTransport) {
+        let (transport, peer) = MemoryTransport::pair();
+        let (reader, writer) = tokio::io::split(peer);
+        let rules = Arc::new(StdMutex::new(vec![Rule {
+            pattern: "-gdb-exit".to_owned(),
+            records: vec!["^exit".to_owned()],
+        }]));
+        let received = Arc::default();
+        let writer = Arc::new(Mutex::new(writer));
+        let task = tokio::spawn(serve(
+            reader,
+            rules.clone(),
+            Arc::clone(&received),
+            writer.clone(),
+        ));
+        let fake = FakeGdb {
+            rules,
+            received,
+            writer,
+            task,
+        };
+        (fake, transport)
+    }
+
+    /// Starts serving and hooks up a controller to it.
+    pub fn controller() -> Result<(FakeGdb, MIController)> {
+        let (fake, transport) = Self::spawn();
+        Ok((fake, MIController::with_transport(transport)?))
+    }
+
+    /// Answers commands starting with `pattern` with `records`.
+    pub fn on<I, S>(&self, pattern: &str, records: I) -> &Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.rules.lock().unwrap().push(Rule {
+            pattern: pattern.to_owned(),
+            records: records.into_iter().map(Into::into).collect(),
+        });
+        self
+    }
+
+    /// Sends a record unprompted, i.e. `*stopped,reason="breakpoint-hit"`.
+    pub async fn emit(&self, record: &str) {
+        let mut writer = self.writer.lock().await;
+        // The controller going away first isn't the test's concern
+        let _ = writer.write_all(format!("{}\n", record).as_bytes()).await;
+    }
+
+    /// Closes the pipe as if GDB had crashed.
+    pub async fn hang_up(&self) {
+        let _ = self.writer.lock().await.shutdown().await;
+    }
+
+    /// Commands received so far, without their tokens.
+    pub fn received(&self) -> Vec<String> {
+        self.received.lock().unwrap().clone()
+    }
+
+    /// Panics unless the commands received so far are exactly `expected`.
+    pub fn assert_received(&self, expected: &[&str]) {
+        let received = self.received();
+        assert_eq!(received, expected, "FakeGdb received unexpected commands");
+    }
+
+    /// Panics unless a command starting with `prefix` was received.
+    pub fn assert_received_command(&self, prefix: &str) {
+        let received = self.received();
+        assert!(
+            received.iter().any(|c| c.starts_with(prefix)),
+            "FakeGdb never received {:?}, got {:?}",
+            prefix,
+            received
+        );
+    }
+}
+
+impl Drop for FakeGdb {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+async fn serve(
+    reader: tokio::io::ReadHalf<DuplexStream>,
+    rules: Arc<StdMutex<Vec<Rule>>>,
+    received: Arc<StdMutex<Vec<String>>>,
+    writer: Arc<Mutex<WriteHalf<DuplexStream>>>,
+) {
+    let mut lines = BufReader::new(reader).lines();
+    while let Ok(Some(line)) = lines.next_line().await {
+        let split = line
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(line.len());
+        let (token, command) = line.split_at(split);
+        received.lock().unwrap().push(command.to_owned());
+        let records = rules
+            .lock()
+            .unwrap()
+            .iter()
+            .rev()
+            .find(|r| command.starts_with(&r.pattern))
+            .map(|r| r.records.clone())
+            .unwrap_or_else(|| {
+                vec![format!(
+                    "^error,msg=\"FakeGdb has no rule for {}\"",
+                    command.replace('"', "\\\"")
+                )]
+            });
+        let mut answer = String::new();
+        for record in records {
+            if record.starts_with('^') {
+                answer.push_str(token);
+            }
+            answer.push_str(&record);
+            answer.push('\n');
+        }
+        answer.push_str("(gdb) \n");
+        if writer
+            .lock()
+            .await
+            .write_all(answer.as_bytes())
+            .await
+            .is_err()
+        {
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{commands::MiCommand,
+                controller::Event,
+                parser::output_types::{AsyncOutput, OutputClass, OOB},
+                types::Error};
+
+    #[tokio::test]
+    async fn test_fake_gdb() {
+        let (fake, controller) = FakeGdb::controller().unwrap();
+        fake.on("-break-insert", ["^done,bkpt={number=\"1\"}"])
+            .on("-exec-run", ["^running", "*running,thread-id=\"all\""]);
+        let mut events = controller.events();
+
+        let record = controller
+            .execute(MiCommand::break_insert("main"))
+            .await
+            .unwrap();
+        assert!(record.get("bkpt").is_some());
+        controller.execute(MiCommand::exec_run()).await.unwrap();
+        assert!(matches!(
+            controller.execute(MiCommand::exec_next()).await,
+            Err(Error::Mi { .. })
+        ));
+
+        fake.emit("*stopped,reason=\"breakpoint-hit\",thread-id=\"1\"")
+            .await;
+        let classes = [OutputClass::Running, OutputClass::Stopped];
+        for class in classes {
+            match events.recv().await.unwrap() {
+                Event::Record(OOB::AsyncRecord(AsyncOutput::ExeAsync(data))) => {
+                    assert_eq!(data.1, class)
+                }
+                other => panic!("unexpected record {:?}", other),
+            }
+        }
+
+        fake.assert_received(&["-break-insert main", "-exec-run", "-exec-next"]);
+        fake.assert_received_command("-exec-run");
+        controller.exit().await.unwrap();
+    }
+}
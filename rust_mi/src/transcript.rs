@@ -0,0 +1,333 @@
+//! Recording of MI sessions and replaying them without GDB.
+//!
+//! Transcripts are saved one entry per line as `<elapsed ms> <dir> <line>`,
+//! where `dir` is `>` for commands sent to GDB and `<` for lines received.
+
+use std::{collections::HashMap,
+          fs::File,
+          io::{self, BufRead, BufReader, BufWriter, Write},
+          path::Path,
+          sync::{Arc, Mutex as StdMutex},
+          time::{Duration, Instant}};
+
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, DuplexStream};
+
+use crate::transport::{BoxedReader, BoxedWriter, MemoryTransport, MiTransport};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Sent,
+    Received,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// Time since the recording started.
+    pub elapsed: Duration,
+    pub direction: Direction,
+    /// The MI line, without its terminator.
+    pub line: String,
+}
+
+#[derive(Debug)]
+struct Log {
+    started: Instant,
+    entries: Vec<Entry>,
+}
+
+/// A shared, append only log of MI traffic. Clones write to the same log.
+#[derive(Debug, Clone)]
+pub struct Transcript {
+    log: Arc<StdMutex<Log>>,
+}
+
+impl Default for Transcript {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Transcript {
+    /// Creates an empty transcript, timestamps are relative to this call.
+    pub fn new() -> Self {
+        Self::from_entries(Vec::new())
+    }
+
+    pub fn from_entries(entries: Vec<Entry>) -> Self {
+        let log = Log {
+            started: Instant::now(),
+            entries,
+        };
+        Self {
+            log: Arc::new(StdMutex::new(log)),
+        }
+    }
+
+    pub fn record(&self, direction: Direction, line: &str) {
+        let mut log = self.log.lock().unwrap();
+        let elapsed = log.started.elapsed();
+        log.entries.push(Entry {
+            elapsed,
+            direction,
+            line: line.to_owned(),
+        });
+    }
+
+    pub fn entries(&self) -> Vec<Entry> {
+        self.log.lock().unwrap().entries.clone()
+    }
+
+    pub fn write_to<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        for entry in &self.log.lock().unwrap().entries {
+            let direction = match entry.direction {
+                Direction::Sent => '>',
+                Direction::Received => '<',
+            };
+            writeln!(
+                writer,
+                "{} {} {}",
+                entry.elapsed.as_millis(),
+                direction,
+                entry.line
+            )?;
+        }
+        writer.flush()
+    }
+
+    pub fn read_from<R: BufRead>(reader: R) -> io::Result<Self> {
+        let mut entries = Vec::new();
+        for (number, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.is_empty() {
+                continue;
+            }
+            entries.push(parse_entry(&line).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("malformed transcript entry at line {}", number + 1),
+                )
+            })?);
+        }
+        Ok(Self::from_entries(entries))
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.write_to(BufWriter::new(File::create(path)?))
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::read_from(BufReader::new(File::open(path)?))
+    }
+}
+
+fn parse_entry(line: &str) -> Option<Entry> {
+    let (elapsed, rest) = line.split_once(' ')?;
+    let (direction, line) = rest.split_once(' ').unwrap_or((rest, ""));
+    let direction = match direction {
+        ">" => Direction::Sent,
+        "<" => Direction::Received,
+        _ => return None,
+    };
+    Some(Entry {
+        elapsed: Duration::from_millis(elapsed.parse().ok()?),
+        direction,
+        line: line.to_owned(),
+    })
+}
+
+/// A command that didn't match the transcript being replayed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Divergence {
+    /// What the transcript says was sent, `None` past its end.
+    pub expected: Option<String>,
+    /// What was actually sent, `None` if the controller hung up early.
+    pub actual: Option<String>,
+}
+
+/// Divergences found so far by a [`ReplayTransport`].
+#[derive(Debug, Clone, Default)]
+pub struct Divergences(Arc<StdMutex<Vec<Divergence>>>);
+
+impl Divergences {
+    pub fn list(&self) -> Vec<Divergence> {
+        self.0.lock().unwrap().clone()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.lock().unwrap().is_empty()
+    }
+
+    fn push(&self, expected: Option<&str>, actual: Option<&str>) {
+        self.0.lock().unwrap().push(Divergence {
+            expected: expected.map(str::to_owned),
+            actual: actual.map(str::to_owned),
+        })
+    }
+}
+
+/// Plays GDB's side of a [`Transcript`] back to a controller.
+///
+/// Received lines are fed in their recorded order, each recorded command
+/// waits for the controller to send one and any mismatch is kept in
+/// [`divergences`](ReplayTransport::divergences).
+///
+/// Commands are compared without their tokens, which depend on what the
+/// controller sent before. Answers get the token the controller actually
+/// used, so a transcript recorded mid-session replays against a fresh one.
+#[derive(Debug)]
+pub struct ReplayTransport {
+    transcript: Transcript,
+    divergences: Divergences,
+    pipe: MemoryTransport,
+    peer: Option<DuplexStream>,
+}
+
+impl ReplayTransport {
+    pub fn new(transcript: Transcript) -> Self {
+        let (pipe, peer) = MemoryTransport::pair();
+        Self {
+            transcript,
+            divergences: Divergences::default(),
+            pipe,
+            peer: Some(peer),
+        }
+    }
+
+    pub fn divergences(&self) -> Divergences {
+        self.divergences.clone()
+    }
+}
+
+impl MiTransport for ReplayTransport {
+    fn take_io(&mut self) -> io::Result<(BoxedReader, BoxedWriter)> {
+        let io = self.pipe.take_io()?;
+        if let Some(peer) = self.peer.take() {
+            tokio::spawn(replay(
+                peer,
+                self.transcript.entries(),
+                self.divergences.clone(),
+            ));
+        }
+        Ok(io)
+    }
+}
+
+// Splits the leading token off an MI line
+fn split_token(line: &str) -> (&str, &str) {
+    let end = line
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(line.len());
+    line.split_at(end)
+}
+
+async fn replay(peer: DuplexStream, entries: Vec<Entry>, divergences: Divergences) {
+    let (reader, mut writer) = tokio::io::split(peer);
+    let mut commands = tokio::io::BufReader::new(reader).lines();
+    // Recorded tokens to the ones the controller used instead
+    let mut tokens = HashMap::new();
+    for entry in entries {
+        match entry.direction {
+            Direction::Received => {
+                let (token, rest) = split_token(&entry.line);
+                let token = tokens.get(token).map_or(token, String::as_str);
+                let line = format!("{}{}\n", token, rest);
+                if writer.write_all(line.as_bytes()).await.is_err() {
+                    return;
+                }
+            }
+            Direction::Sent => match commands.next_line().await {
+                Ok(Some(command)) => {
+                    let (recorded_token, expected) = split_token(&entry.line);
+                    let (token, actual) = split_token(&command);
+                    if actual != expected {
+                        divergences.push(Some(&entry.line), Some(&command));
+                    }
+                    // Answered either way, a diverging controller shouldn't hang
+                    if !recorded_token.is_empty() {
+                        tokens.insert(recorded_token.to_owned(), token.to_owned());
+                    }
+                }
+                _ => {
+                    divergences.push(Some(&entry.line), None);
+                    return;
+                }
+            },
+        }
+    }
+    // Anything sent after the transcript ran out is unexpected
+    while let Ok(Some(command)) = commands.next_line().await {
+        divergences.push(None, Some(&command));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{commands::MiCommand, testing::FakeGdb, MIController};
+
+    #[test]
+    fn test_round_trip() {
+        let transcript = Transcript::from_entries(vec![
+            Entry {
+                elapsed: Duration::from_millis(3),
+                direction: Direction::Sent,
+                line: "1-exec-run".to_owned(),
+            },
+            Entry {
+                elapsed: Duration::from_millis(15),
+                direction: Direction::Received,
+                line: "1^running".to_owned(),
+            },
+        ]);
+        let mut saved = Vec::new();
+        transcript.write_to(&mut saved).unwrap();
+        assert_eq!(saved, b"3 > 1-exec-run\n15 < 1^running\n");
+        let loaded = Transcript::read_from(saved.as_slice()).unwrap();
+        assert_eq!(loaded.entries(), transcript.entries());
+        assert!(Transcript::read_from(&b"12 ? nope\n"[..]).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_record_and_replay() {
+        let (fake, controller) = FakeGdb::controller().unwrap();
+        fake.on("-break-insert", ["^done,bkpt={number=\"1\"}"])
+            .on("-gdb-set", ["^done"]);
+        // Recorded mid-session, tokens don't start at 1
+        controller
+            .execute(MiCommand::gdb_set("confirm", "off"))
+            .await
+            .unwrap();
+        let transcript = controller.start_recording();
+        controller
+            .execute(MiCommand::break_insert("main"))
+            .await
+            .unwrap();
+        assert!(controller.stop_recording().is_some());
+        let entries = transcript.entries();
+        assert_eq!(entries[0].direction, Direction::Sent);
+        assert_eq!(entries[0].line, "2-break-insert main");
+        assert_eq!(entries[1].line, "2^done,bkpt={number=\"1\"}");
+
+        let replay = ReplayTransport::new(transcript.clone());
+        let divergences = replay.divergences();
+        let controller = MIController::with_transport(replay).unwrap();
+        let record = controller
+            .execute(MiCommand::break_insert("main"))
+            .await
+            .unwrap();
+        assert!(record.get("bkpt").is_some());
+        assert!(divergences.is_empty());
+
+        let replay = ReplayTransport::new(transcript);
+        let divergences = replay.divergences();
+        let controller = MIController::with_transport(replay).unwrap();
+        let _ = controller.execute(MiCommand::break_insert("foo")).await;
+        assert_eq!(
+            divergences.list(),
+            vec![Divergence {
+                expected: Some("2-break-insert main".to_owned()),
+                actual: Some("1-break-insert foo".to_owned()),
+            }]
+        );
+    }
+}
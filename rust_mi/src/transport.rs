@@ -0,0 +1,284 @@
+use std::{collections::VecDeque,
+          fmt,
+          future::Future,
+          io,
+          pin::Pin,
+          process::{ExitStatus, Stdio},
+          sync::{Arc, Mutex as StdMutex},
+          time::Duration};
+
+use tokio::{io::{AsyncBufReadExt, AsyncRead, AsyncWrite, BufReader, DuplexStream},
+            net::{TcpStream, ToSocketAddrs},
+            process::{ChildStdin, ChildStdout, Command},
+            sync::watch,
+            task::JoinHandle};
+
+use crate::{config::GdbConfig, types::BackendExit};
+
+pub type BoxedReader = Box<dyn AsyncRead + Send + Unpin>;
+pub type BoxedWriter = Box<dyn AsyncWrite + Send + Unpin>;
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+// Buffer size for each direction of an in memory pipe
+const MEMORY_CAPACITY: usize = 64 * 1024;
+
+// How much of GDB's stderr is kept around to explain a crash
+const STDERR_LINES: usize = 20;
+
+// How long to wait for stderr to drain once the process is gone
+const STDERR_GRACE: Duration = Duration::from_millis(200);
+
+/// Something [`MIController`](crate::MIController) can talk MI through.
+///
+/// The controller takes the reading and writing halves once when it starts
+/// and calls [`shutdown`](MiTransport::shutdown) when it's done with GDB.
+pub trait MiTransport: Send + fmt::Debug {
+    /// Hands out the stream GDB writes MI records to and the one it reads
+    /// commands from. Called exactly once.
+    fn take_io(&mut self) -> io::Result<(BoxedReader, BoxedWriter)>;
+
+    /// Releases whatever is behind the transport once GDB was asked to exit.
+    fn shutdown(&mut self) -> BoxFuture<'_, io::Result<()>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    /// A future resolving when the backend goes away, for transports that
+    /// can tell. Called once, before [`take_io`](MiTransport::take_io).
+    /// Transports returning `None` are considered gone when their reading
+    /// half hits EOF.
+    fn exited(&mut self) -> Option<BoxFuture<'static, BackendExit>> {
+        None
+    }
+}
+
+/// GDB running as a child process, talking MI over its stdin and stdout.
+///
+/// The process is watched from a background task which also keeps the tail
+/// of its stderr, both end up in the [`BackendExit`] reported on a crash.
+#[derive(Debug)]
+pub struct ProcessTransport {
+    stdio: Option<(ChildStdout, ChildStdin)>,
+    status: watch::Receiver<Option<ExitStatus>>,
+    stderr: Arc<StdMutex<VecDeque<String>>>,
+    stderr_open: watch::Receiver<()>,
+    monitor: JoinHandle<()>,
+}
+
+impl ProcessTransport {
+    /// Spawns `command` with piped stdio, the child is killed if the
+    /// transport is dropped.
+    pub fn spawn(command: &mut Command) -> io::Result<Self> {
+        let mut child = command
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()?;
+        let stdio = match (child.stdout.take(), child.stdin.take()) {
+            (Some(stdout), Some(stdin)) => Some((stdout, stdin)),
+            _ => None,
+        };
+
+        let stderr = Arc::new(StdMutex::new(VecDeque::new()));
+        // The sender is dropped once stderr hits EOF
+        let (stderr_tx, stderr_open) = watch::channel(());
+        if let Some(pipe) = child.stderr.take() {
+            let stderr = stderr.clone();
+            tokio::spawn(async move {
+                let mut lines = BufReader::new(pipe).lines();
+                while let Ok(Some(line)) = lines.next_line().await {
+                    let mut tail = stderr.lock().unwrap();
+                    if tail.len() == STDERR_LINES {
+                        tail.pop_front();
+                    }
+                    tail.push_back(line);
+                }
+                drop(stderr_tx);
+            });
+        }
+
+        let (status_tx, status) = watch::channel(None);
+        let monitor = tokio::spawn(async move {
+            if let Ok(status) = child.wait().await {
+                let _ = status_tx.send(Some(status));
+            }
+        });
+        Ok(Self {
+            stdio,
+            status,
+            stderr,
+            stderr_open,
+            monitor,
+        })
+    }
+
+    /// Spawns GDB as set up in `config`, without checking its version.
+    pub fn gdb(config: &GdbConfig) -> io::Result<Self> {
+        Self::spawn(&mut config.command())
+    }
+}
+
+async fn wait_status(mut status: watch::Receiver<Option<ExitStatus>>) -> Option<ExitStatus> {
+    loop {
+        if let Some(status) = *status.borrow() {
+            return Some(status);
+        }
+        status.changed().await.ok()?;
+    }
+}
+
+impl MiTransport for ProcessTransport {
+    fn take_io(&mut self) -> io::Result<(BoxedReader, BoxedWriter)> {
+        match self.stdio.take() {
+            Some((stdout, stdin)) => Ok((Box::new(stdout), Box::new(stdin))),
+            None => Err(io::Error::new(
+                io::ErrorKind::BrokenPipe,
+                "gdb stdio already taken",
+            )),
+        }
+    }
+
+    fn shutdown(&mut self) -> BoxFuture<'_, io::Result<()>> {
+        let status = self.status.clone();
+        Box::pin(async move {
+            wait_status(status).await;
+            Ok(())
+        })
+    }
+
+    fn exited(&mut self) -> Option<BoxFuture<'static, BackendExit>> {
+        let status = self.status.clone();
+        let stderr = self.stderr.clone();
+        let mut stderr_open = self.stderr_open.clone();
+        Some(Box::pin(async move {
+            let status = wait_status(status).await;
+            let _ = tokio::time::timeout(STDERR_GRACE, stderr_open.changed()).await;
+            let stderr = stderr.lock().unwrap().iter().cloned().collect();
+            BackendExit { status, stderr }
+        }))
+    }
+}
+
+impl Drop for ProcessTransport {
+    fn drop(&mut self) {
+        // Drops the child, which kills it
+        self.monitor.abort();
+    }
+}
+
+/// MI over a TCP socket, i.e. a GDB started with its console redirected to a
+/// socket by `socat` or similar.
+#[derive(Debug)]
+pub struct TcpTransport {
+    stream: Option<TcpStream>,
+}
+
+impl TcpTransport {
+    pub async fn connect<A: ToSocketAddrs>(addr: A) -> io::Result<Self> {
+        Ok(Self::from(TcpStream::connect(addr).await?))
+    }
+}
+
+impl From<TcpStream> for TcpTransport {
+    fn from(stream: TcpStream) -> Self {
+        Self {
+            stream: Some(stream),
+        }
+    }
+}
+
+impl MiTransport for TcpTransport {
+    fn take_io(&mut self) -> io::Result<(BoxedReader, BoxedWriter)> {
+        let stream = self
+            .stream
+            .take()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotConnected, "socket already taken"))?;
+        let (reader, writer) = stream.into_split();
+        Ok((Box::new(reader), Box::new(writer)))
+    }
+}
+
+/// An in memory pipe, the other end is handed to whoever plays GDB.
+#[derive(Debug)]
+pub struct MemoryTransport {
+    stream: Option<DuplexStream>,
+}
+
+impl MemoryTransport {
+    /// Returns the transport together with the peer end of the pipe: MI
+    /// records written to the peer reach the controller and commands sent
+    /// by the controller can be read from it.
+    pub fn pair() -> (Self, DuplexStream) {
+        let (ours, theirs) = tokio::io::duplex(MEMORY_CAPACITY);
+        (Self { stream: Some(ours) }, theirs)
+    }
+}
+
+impl MiTransport for MemoryTransport {
+    fn take_io(&mut self) -> io::Result<(BoxedReader, BoxedWriter)> {
+        let stream = self
+            .stream
+            .take()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotConnected, "pipe already taken"))?;
+        let (reader, writer) = tokio::io::split(stream);
+        Ok((Box::new(reader), Box::new(writer)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::{io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+                net::TcpListener};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_memory_transport() {
+        let (mut transport, peer) = MemoryTransport::pair();
+        let (reader, mut writer) = transport.take_io().unwrap();
+        assert!(transport.take_io().is_err());
+
+        let (peer_reader, mut peer_writer) = tokio::io::split(peer);
+        writer.write_all(b"1-exec-run\n").await.unwrap();
+        peer_writer.write_all(b"1^running\n").await.unwrap();
+
+        let mut line = String::new();
+        BufReader::new(peer_reader)
+            .read_line(&mut line)
+            .await
+            .unwrap();
+        assert_eq!(line, "1-exec-run\n");
+        line.clear();
+        BufReader::new(reader).read_line(&mut line).await.unwrap();
+        assert_eq!(line, "1^running\n");
+    }
+
+    #[tokio::test]
+    async fn test_process_exit() {
+        let mut transport = ProcessTransport::spawn(
+            Command::new("sh").args(["-c", "echo first >&2; echo second >&2; exit 3"]),
+        )
+        .unwrap();
+        let exit = transport.exited().unwrap().await;
+        assert_eq!(exit.status.and_then(|s| s.code()), Some(3));
+        assert_eq!(exit.stderr, ["first", "second"]);
+        assert!(transport.take_io().is_ok());
+        assert!(transport.shutdown().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_tcp_transport() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let mut transport = TcpTransport::connect(listener.local_addr().unwrap())
+            .await
+            .unwrap();
+        let (mut peer, _) = listener.accept().await.unwrap();
+        let (reader, _writer) = transport.take_io().unwrap();
+
+        peer.write_all(b"(gdb)\n").await.unwrap();
+        let mut line = String::new();
+        BufReader::new(reader).read_line(&mut line).await.unwrap();
+        assert_eq!(line, "(gdb)\n");
+        assert!(transport.shutdown().await.is_ok());
+    }
+}
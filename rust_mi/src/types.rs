@@ -1 +1,130 @@
+use std::{fmt, path::PathBuf, process::ExitStatus, time::Duration};
 
+/// How the backend behind a transport went away.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BackendExit {
+    /// Exit status of the GDB process, when there is one.
+    pub status: Option<ExitStatus>,
+    /// The last lines GDB wrote to stderr.
+    pub stderr: Vec<String>,
+}
+
+impl fmt::Display for BackendExit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.status {
+            Some(status) => write!(f, "gdb exited ({})", status)?,
+            None => write!(f, "gdb hung up")?,
+        }
+        for line in &self.stderr {
+            write!(f, "\n{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+/// What a core dump says about how the program ended.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CoreDump {
+    /// The signal that terminated the program, i.e. `SIGSEGV`.
+    pub signal: Option<String>,
+    /// GDB's description of the signal, i.e. `Segmentation fault`.
+    pub description: Option<String>,
+    /// The thread that got the signal.
+    pub thread: Option<u32>,
+}
+
+impl CoreDump {
+    // Picks the signal out of `Program terminated with signal SIGSEGV,
+    // Segmentation fault.`
+    pub(crate) fn parse_console(&mut self, text: &str) {
+        const PREFIX: &str = "Program terminated with signal ";
+        let line = match text.lines().find_map(|l| l.strip_prefix(PREFIX)) {
+            Some(line) => line.trim_end_matches('.'),
+            None => return,
+        };
+        let (signal, description) = match line.split_once(", ") {
+            Some((signal, description)) => (signal, Some(description)),
+            None => (line, None),
+        };
+        self.signal = Some(signal.to_owned());
+        self.description = description.map(str::to_owned);
+    }
+}
+
+/// Errors returned by [`crate::MIController`].
+#[derive(Debug)]
+pub enum Error {
+    Io(std::io::Error),
+    /// GDB answered the command with `^error`.
+    Mi {
+        msg: String,
+        code: Option<String>,
+    },
+    /// The controller stopped reading from GDB before the command got an answer.
+    Closed,
+    /// No answer arrived within the given time.
+    Timeout(Duration),
+    /// The caller gave up on the command.
+    Cancelled,
+    /// GDB died or the connection to it was lost.
+    BackendExited(BackendExit),
+    /// The GDB executable couldn't be found.
+    GdbNotFound(PathBuf),
+    /// The GDB executable is too old or isn't GDB at all.
+    UnsupportedGdb {
+        program: PathBuf,
+        version: String,
+    },
+    /// The target is a core dump, the program can't be run.
+    PostMortem,
+    /// The command needs a stopped target and was told not to wait, see
+    /// [`crate::WhileRunning::Fail`].
+    Running,
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "io error: {}", e),
+            Error::Mi {
+                msg,
+                code: Some(code),
+            } => write!(f, "{} ({})", msg, code),
+            Error::Mi { msg, code: None } => write!(f, "{}", msg),
+            Error::Closed => write!(f, "connection to gdb closed"),
+            Error::Timeout(after) => write!(f, "gdb didn't answer after {:?}", after),
+            Error::Cancelled => write!(f, "command cancelled"),
+            Error::BackendExited(exit) => write!(f, "{}", exit),
+            Error::GdbNotFound(program) => write!(
+                f,
+                "couldn't find gdb at {}, is it installed?",
+                program.display()
+            ),
+            Error::UnsupportedGdb { program, version } => write!(
+                f,
+                "{} reports version {:?}, at least GDB 9.1 is needed",
+                program.display(),
+                version
+            ),
+            Error::PostMortem => write!(f, "can't run a program from a core dump"),
+            Error::Running => write!(f, "the program is running, pause it first"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
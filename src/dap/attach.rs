@@ -0,0 +1,211 @@
+use std::fmt;
+
+use rust_mi::{commands::{MiCommand, Target},
+              types::{CoreDump, Error},
+              MIController};
+use serde::Deserialize;
+
+use super::types::StoppedEventBody;
+
+/// Arguments of the `attach` request, the protocol leaves all but
+/// `__restart` to the adapter.
+#[derive(Clone, PartialEq, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttachArguments {
+    /// Data from a previous, restarted session.
+    #[serde(rename = "__restart")]
+    pub restart: Option<serde_json::Value>,
+    /// Executable to load symbols from.
+    pub program: Option<String>,
+    /// Local process to attach to.
+    pub process_id: Option<u32>,
+    /// gdbserver to connect to.
+    pub gdbserver: Option<GdbserverConfig>,
+    /// Core dump of `program` to inspect post-mortem.
+    pub core_file: Option<String>,
+}
+
+/// A gdbserver (or any remote stub) to debug through.
+#[derive(Clone, PartialEq, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GdbserverConfig {
+    /// `host:port` the server listens on.
+    pub address: String,
+    /// Uses `extended-remote`, so the connection outlives the program.
+    #[serde(default)]
+    pub extended: bool,
+    /// Where GDB finds the target's libraries, `target:` fetches them from
+    /// the server.
+    pub sysroot: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum AttachError {
+    /// Neither a process, a server nor a core file was given.
+    NoTarget,
+    /// A core file was given without the executable that dumped it.
+    NoProgram,
+    Gdb(Error),
+}
+
+impl fmt::Display for AttachError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AttachError::NoTarget => {
+                write!(f, "attach needs a processId, a gdbserver or a coreFile")
+            }
+            AttachError::NoProgram => write!(f, "a coreFile needs the program that dumped it"),
+            AttachError::Gdb(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl From<Error> for AttachError {
+    fn from(e: Error) -> Self {
+        AttachError::Gdb(e)
+    }
+}
+
+impl GdbserverConfig {
+    pub fn target(&self) -> Target {
+        if self.extended {
+            Target::ExtendedRemote(self.address.clone())
+        } else {
+            Target::Remote(self.address.clone())
+        }
+    }
+}
+
+impl AttachArguments {
+    /// Loads the program, if any, and attaches `controller` to the target.
+    ///
+    /// A core file is already stopped for good, its `stopped` event is
+    /// returned so it can be sent once the client is configured.
+    pub async fn attach(
+        &self,
+        controller: &MIController,
+    ) -> Result<Option<StoppedEventBody>, AttachError> {
+        if let Some(core) = &self.core_file {
+            let program = self.program.as_deref().ok_or(AttachError::NoProgram)?;
+            let dump = controller.load_core(program, core).await?;
+            return Ok(Some(core_stopped(&dump)));
+        }
+        if self.process_id.is_none() && self.gdbserver.is_none() {
+            return Err(AttachError::NoTarget);
+        }
+        if let Some(program) = &self.program {
+            controller
+                .execute(MiCommand::file_exec_and_symbols(program.as_str()))
+                .await?;
+        }
+        if let Some(server) = &self.gdbserver {
+            controller
+                .connect_remote(&server.target(), server.sysroot.as_deref())
+                .await?;
+        } else if let Some(pid) = self.process_id {
+            controller.execute(MiCommand::target_attach(pid)).await?;
+        }
+        Ok(None)
+    }
+}
+
+// The reason is the signal itself so it shows up as is in the call stack
+// view, i.e. "Paused on SIGSEGV"
+fn core_stopped(dump: &CoreDump) -> StoppedEventBody {
+    StoppedEventBody {
+        all_threads_stopped: Some(true),
+        description: dump.signal.as_ref().map(|signal| match &dump.description {
+            Some(description) => format!("Terminated by {} ({})", signal, description),
+            None => format!("Terminated by {}", signal),
+        }),
+        hit_breakpoint_ids: None,
+        preserve_focus_hint: None,
+        reason: dump
+            .signal
+            .clone()
+            .unwrap_or_else(|| "exception".to_owned()),
+        text: dump.description.clone(),
+        thread_id: dump.thread.map(i64::from),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_mi::testing::FakeGdb;
+
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let arguments: AttachArguments = serde_json::from_str(
+            r#"{"program": "/srv/app", "gdbserver": {"address": "db:2345", "extended": true}}"#,
+        )
+        .unwrap();
+        let server = arguments.gdbserver.unwrap();
+        assert_eq!(arguments.program.as_deref(), Some("/srv/app"));
+        assert_eq!(
+            server.target(),
+            Target::ExtendedRemote("db:2345".to_owned())
+        );
+        assert_eq!(server.sysroot, None);
+    }
+
+    #[tokio::test]
+    async fn test_attach_gdbserver() {
+        let (fake, controller) = FakeGdb::controller().unwrap();
+        fake.on("-file-exec-and-symbols", ["^done"])
+            .on("-gdb-set", ["^done"])
+            .on("-target-select", ["^connected"]);
+        let arguments = AttachArguments {
+            program: Some("/srv/app".to_owned()),
+            gdbserver: Some(GdbserverConfig {
+                address: "localhost:2345".to_owned(),
+                extended: false,
+                sysroot: Some("target:".to_owned()),
+            }),
+            ..Default::default()
+        };
+        assert_eq!(arguments.attach(&controller).await.unwrap(), None);
+        fake.assert_received(&[
+            "-file-exec-and-symbols /srv/app",
+            "-gdb-set sysroot target:",
+            "-target-select remote localhost:2345",
+        ]);
+
+        let nothing = AttachArguments::default();
+        assert!(matches!(
+            nothing.attach(&controller).await,
+            Err(AttachError::NoTarget)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_attach_core() {
+        let (fake, controller) = FakeGdb::controller().unwrap();
+        fake.on("-file-exec-and-symbols", ["^done"]).on(
+            "-target-select core",
+            [
+                "~\"Program terminated with signal SIGABRT, Aborted.\\n\"",
+                "*stopped,thread-id=\"2\",stopped-threads=\"all\"",
+                "^connected",
+            ],
+        );
+        let arguments: AttachArguments =
+            serde_json::from_str(r#"{"program": "./app", "coreFile": "core.17"}"#).unwrap();
+        let stopped = arguments.attach(&controller).await.unwrap().unwrap();
+        assert_eq!(stopped.reason, "SIGABRT");
+        assert_eq!(stopped.text.as_deref(), Some("Aborted"));
+        assert_eq!(stopped.thread_id, Some(2));
+        assert_eq!(stopped.all_threads_stopped, Some(true));
+        assert!(controller.execute(MiCommand::exec_next()).await.is_err());
+
+        let no_program = AttachArguments {
+            core_file: Some("core.17".to_owned()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            no_program.attach(&controller).await,
+            Err(AttachError::NoProgram)
+        ));
+    }
+}
@@ -0,0 +1,174 @@
+use std::fmt::Write;
+
+use super::types::InitializeRequestArguments;
+
+/// What the client said it can handle in `initialize`.
+///
+/// GDB's lines and columns start at 1 and its paths are native ones, every
+/// handler converts through here before talking to the client, and leaves
+/// out whatever the client didn't ask for.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ClientCapabilities {
+    pub lines_start_at_1: bool,
+    pub columns_start_at_1: bool,
+    /// Paths are `file://` URIs rather than native paths.
+    pub uri_paths: bool,
+    pub variable_type: bool,
+    pub memory_references: bool,
+    pub run_in_terminal: bool,
+    pub progress_reporting: bool,
+}
+
+impl Default for ClientCapabilities {
+    // What the protocol assumes when the client doesn't say
+    fn default() -> Self {
+        ClientCapabilities {
+            lines_start_at_1: true,
+            columns_start_at_1: true,
+            uri_paths: false,
+            variable_type: false,
+            memory_references: false,
+            run_in_terminal: false,
+            progress_reporting: false,
+        }
+    }
+}
+
+impl From<&InitializeRequestArguments> for ClientCapabilities {
+    fn from(arguments: &InitializeRequestArguments) -> Self {
+        ClientCapabilities {
+            lines_start_at_1: arguments.lines_start_at_1.unwrap_or(true),
+            columns_start_at_1: arguments.columns_start_at_1.unwrap_or(true),
+            uri_paths: arguments.path_format.as_deref() == Some("uri"),
+            variable_type: arguments.supports_variable_type.unwrap_or(false),
+            memory_references: arguments.supports_memory_references.unwrap_or(false),
+            run_in_terminal: arguments.supports_run_in_terminal_request.unwrap_or(false),
+            progress_reporting: arguments.supports_progress_reporting.unwrap_or(false),
+        }
+    }
+}
+
+impl ClientCapabilities {
+    /// Converts one of GDB's lines for the client.
+    pub fn line(&self, line: i64) -> i64 {
+        if self.lines_start_at_1 {
+            line
+        } else {
+            line - 1
+        }
+    }
+
+    /// Converts one of GDB's columns for the client.
+    pub fn column(&self, column: i64) -> i64 {
+        if self.columns_start_at_1 {
+            column
+        } else {
+            column - 1
+        }
+    }
+
+    /// Converts one of GDB's paths for the client.
+    pub fn path(&self, path: &str) -> String {
+        if !self.uri_paths {
+            return path.to_owned();
+        }
+        let mut uri = String::from("file://");
+        for byte in path.bytes() {
+            match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                    uri.push(byte as char)
+                }
+                _ => write!(uri, "%{:02X}", byte).unwrap(),
+            }
+        }
+        uri
+    }
+
+    /// Converts a path from the client for GDB.
+    pub fn gdb_path(&self, path: &str) -> String {
+        let encoded = match path.strip_prefix("file://") {
+            Some(encoded) if self.uri_paths => encoded,
+            // Some clients send plain paths anyway
+            _ => return path.to_owned(),
+        };
+        let (encoded, mut decoded) = (encoded.as_bytes(), Vec::with_capacity(encoded.len()));
+        let mut i = 0;
+        while i < encoded.len() {
+            let escaped = encoded
+                .get(i + 1..i + 3)
+                .filter(|_| encoded[i] == b'%')
+                .and_then(|hex| u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok());
+            match escaped {
+                Some(byte) => {
+                    decoded.push(byte);
+                    i += 3;
+                }
+                None => {
+                    decoded.push(encoded[i]);
+                    i += 1;
+                }
+            }
+        }
+        String::from_utf8_lossy(&decoded).into_owned()
+    }
+
+    /// A memory reference to `address`, if the client takes them.
+    pub fn memory_reference(&self, address: Option<&str>) -> Option<String> {
+        address
+            .filter(|_| self.memory_references)
+            .map(str::to_owned)
+    }
+
+    /// The type of a variable, if the client shows them.
+    pub fn variable_type(&self, type_: Option<String>) -> Option<String> {
+        type_.filter(|_| self.variable_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn client(arguments: &str) -> ClientCapabilities {
+        let arguments: InitializeRequestArguments = serde_json::from_str(arguments).unwrap();
+        ClientCapabilities::from(&arguments)
+    }
+
+    #[test]
+    fn test_defaults() {
+        let defaults = client(r#"{"adapterID":"gdb"}"#);
+        assert_eq!(defaults, ClientCapabilities::default());
+        assert_eq!(defaults.line(7), 7);
+        assert_eq!(defaults.column(1), 1);
+        assert_eq!(defaults.path("/src/my app.c"), "/src/my app.c");
+        assert_eq!(defaults.memory_reference(Some("0x401136")), None);
+        assert_eq!(defaults.variable_type(Some("int".to_owned())), None);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let client = client(
+            r#"{"adapterID":"gdb","linesStartAt1":false,"columnsStartAt1":false,
+                "pathFormat":"uri","supportsVariableType":true,
+                "supportsMemoryReferences":true,"supportsRunInTerminalRequest":true,
+                "supportsProgressReporting":true}"#,
+        );
+        assert!(client.run_in_terminal && client.progress_reporting);
+        assert_eq!(client.line(7), 6);
+        assert_eq!(client.column(1), 0);
+        assert_eq!(
+            client.memory_reference(Some("0x401136")),
+            Some("0x401136".to_owned())
+        );
+        assert_eq!(
+            client.variable_type(Some("int".to_owned())),
+            Some("int".to_owned())
+        );
+
+        assert_eq!(client.path("/src/my app.c"), "file:///src/my%20app.c");
+        assert_eq!(client.gdb_path("file:///src/my%20app.c"), "/src/my app.c");
+        assert_eq!(client.gdb_path("file:///src/caf%C3%A9.c"), "/src/café.c");
+        assert_eq!(client.gdb_path("file:///src/100%.c"), "/src/100%.c");
+        assert_eq!(client.gdb_path("/src/app.c"), "/src/app.c");
+    }
+}
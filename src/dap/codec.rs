@@ -0,0 +1,335 @@
+use std::{fmt, io, str};
+
+use bytes::{Buf, BufMut, BytesMut};
+use serde::{Deserialize, Serialize};
+use tokio_util::codec::{Decoder, Encoder};
+
+use super::{messages::{Event, Response},
+            requests::Incoming};
+
+const HEADER_END: &[u8] = b"\r\n\r\n";
+
+// A header this long without its terminator means we're not reading DAP
+const MAX_HEADER: usize = 1024;
+// Bodies are buffered whole, a bigger one is skipped rather than allocated
+const MAX_BODY: usize = 16 * 1024 * 1024;
+
+/// Any message going either way.
+///
+/// Requests are decoded already parsed and can't be encoded, reverse requests
+/// go out as [`types::Request`](super::types::Request) instead.
+#[derive(Clone, PartialEq, Debug, Serialize)]
+#[serde(untagged)]
+pub enum Message {
+    #[serde(skip_serializing)]
+    Request(Incoming),
+    Response(Response),
+    Event(Event),
+}
+
+impl<'de> Deserialize<'de> for Message {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        // Every message carries its kind in `type`
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let message = match value.get("type").and_then(|t| t.as_str()) {
+            Some("request") => serde_json::from_value(value).map(Message::Request),
+            Some("response") => serde_json::from_value(value).map(Message::Response),
+            Some("event") => serde_json::from_value(value).map(Message::Event),
+            Some(other) => {
+                return Err(D::Error::custom(format!(
+                    "unknown message type {:?}",
+                    other
+                )))
+            }
+            None => return Err(D::Error::missing_field("type")),
+        };
+        message.map_err(D::Error::custom)
+    }
+}
+
+#[derive(Debug)]
+pub enum CodecError {
+    Io(io::Error),
+    /// The header is missing `Content-Length` or isn't a header at all.
+    Header(String),
+    /// The body isn't a valid message, it was skipped.
+    Json(serde_json::Error),
+    /// The body is bigger than this codec takes, it's being skipped.
+    TooLarge(usize),
+}
+
+impl fmt::Display for CodecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CodecError::Io(e) => write!(f, "io error: {}", e),
+            CodecError::Header(msg) => write!(f, "malformed header: {}", msg),
+            CodecError::Json(e) => write!(f, "malformed message: {}", e),
+            CodecError::TooLarge(length) => {
+                write!(
+                    f,
+                    "message of {} bytes is over the {} byte limit",
+                    length, MAX_BODY
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for CodecError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CodecError::Io(e) => Some(e),
+            CodecError::Header(_) | CodecError::TooLarge(_) => None,
+            CodecError::Json(e) => Some(e),
+        }
+    }
+}
+
+impl From<io::Error> for CodecError {
+    fn from(e: io::Error) -> Self {
+        CodecError::Io(e)
+    }
+}
+
+/// Frames DAP messages: a `Content-Length: <n>` header, an empty line and
+/// `n` bytes of JSON.
+///
+/// Anything serializable can be encoded. A malformed header or body, or a
+/// body over 16 MiB, is reported once and skipped, so decoding can carry on
+/// with the next message.
+#[derive(Debug, Default)]
+pub struct DapCodec {
+    // Length of the body whose header was already consumed
+    content_length: Option<usize>,
+    // What's left of a body too large to decode
+    skipping: usize,
+}
+
+impl DapCodec {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+fn parse_header(header: &[u8]) -> Result<usize, CodecError> {
+    let header = str::from_utf8(header).map_err(|_| CodecError::Header("not utf-8".to_owned()))?;
+    let mut content_length = None;
+    for field in header.split("\r\n") {
+        let (name, value) = field
+            .split_once(':')
+            .ok_or_else(|| CodecError::Header(format!("{:?} isn't a header field", field)))?;
+        // Other fields, i.e. `Content-Type`, don't change anything
+        if name.trim().eq_ignore_ascii_case("content-length") {
+            let length = value.trim().parse().map_err(|_| {
+                CodecError::Header(format!("invalid Content-Length {:?}", value.trim()))
+            })?;
+            content_length = Some(length);
+        }
+    }
+    content_length.ok_or_else(|| CodecError::Header("no Content-Length".to_owned()))
+}
+
+impl Decoder for DapCodec {
+    type Item = Message;
+    type Error = CodecError;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Message>, CodecError> {
+        if self.skipping > 0 {
+            let skipped = self.skipping.min(src.len());
+            src.advance(skipped);
+            self.skipping -= skipped;
+            if self.skipping > 0 {
+                return Ok(None);
+            }
+        }
+        let length = match self.content_length {
+            Some(length) => length,
+            None => {
+                let end = match src.windows(HEADER_END.len()).position(|w| w == HEADER_END) {
+                    Some(end) => end,
+                    None if src.len() > MAX_HEADER => {
+                        src.clear();
+                        return Err(CodecError::Header("header too long".to_owned()));
+                    }
+                    None => return Ok(None),
+                };
+                let header = src.split_to(end + HEADER_END.len());
+                let length = parse_header(&header[..end])?;
+                if length > MAX_BODY {
+                    self.skipping = length;
+                    return Err(CodecError::TooLarge(length));
+                }
+                self.content_length = Some(length);
+                length
+            }
+        };
+        if src.len() < length {
+            src.reserve(length - src.len());
+            return Ok(None);
+        }
+        self.content_length = None;
+        let body = src.split_to(length);
+        serde_json::from_slice(&body)
+            .map(Some)
+            .map_err(CodecError::Json)
+    }
+}
+
+impl<T: Serialize> Encoder<T> for DapCodec {
+    type Error = CodecError;
+
+    fn encode(&mut self, item: T, dst: &mut BytesMut) -> Result<(), CodecError> {
+        let body = serde_json::to_vec(&item).map_err(CodecError::Json)?;
+        let header = format!("Content-Length: {}\r\n\r\n", body.len());
+        dst.reserve(header.len() + body.len());
+        dst.put_slice(header.as_bytes());
+        dst.put_slice(&body);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(body: &str) -> String {
+        format!("Content-Length: {}\r\n\r\n{}", body.len(), body)
+    }
+
+    const INITIALIZE: &str =
+        r#"{"seq":1,"type":"request","command":"initialize","arguments":{"adapterID":"gdb"}}"#;
+    const INITIALIZED: &str = r#"{"seq":2,"type":"event","event":"initialized"}"#;
+
+    #[test]
+    fn test_decode() {
+        let mut codec = DapCodec::new();
+        let mut src = BytesMut::from(frame(INITIALIZE).as_str());
+        match codec.decode(&mut src).unwrap() {
+            Some(Message::Request(request)) => {
+                assert_eq!(request.seq, 1);
+                assert_eq!(request.command(), "initialize");
+            }
+            other => panic!("expected a request, got {:?}", other),
+        }
+        assert!(src.is_empty());
+        assert_eq!(codec.decode(&mut src).unwrap(), None);
+    }
+
+    #[test]
+    fn test_partial_reads() {
+        let mut codec = DapCodec::new();
+        let mut src = BytesMut::new();
+        let framed = frame(INITIALIZED);
+        // Split inside the header and inside the body
+        for chunk in [&framed[..7], &framed[7..30], &framed[30..]] {
+            assert_eq!(codec.decode(&mut src).unwrap(), None);
+            src.extend_from_slice(chunk.as_bytes());
+        }
+        assert!(matches!(
+            codec.decode(&mut src).unwrap(),
+            Some(Message::Event(event)) if event.event == "initialized"
+        ));
+    }
+
+    #[test]
+    fn test_multiple_messages() {
+        let mut codec = DapCodec::new();
+        let response =
+            r#"{"seq":3,"type":"response","request_seq":1,"success":true,"command":"initialize"}"#;
+        let mut src = BytesMut::from(
+            format!(
+                "{}{}{}",
+                frame(INITIALIZE),
+                frame(response),
+                frame(INITIALIZED)
+            )
+            .as_str(),
+        );
+        assert!(matches!(
+            codec.decode(&mut src).unwrap(),
+            Some(Message::Request(_))
+        ));
+        assert!(matches!(
+            codec.decode(&mut src).unwrap(),
+            Some(Message::Response(r)) if r.request_seq == 1 && r.success
+        ));
+        assert!(matches!(
+            codec.decode(&mut src).unwrap(),
+            Some(Message::Event(_))
+        ));
+        assert_eq!(codec.decode(&mut src).unwrap(), None);
+    }
+
+    #[test]
+    fn test_malformed() {
+        let mut codec = DapCodec::new();
+        let mut src = BytesMut::from(
+            format!(
+                "Content-Lenght: 2\r\n\r\nContent-Length: x\r\n\r\n{}{}",
+                frame(r#"{"seq":1,"type":"gossip"}"#),
+                frame(INITIALIZED)
+            )
+            .as_str(),
+        );
+        assert!(matches!(codec.decode(&mut src), Err(CodecError::Header(_))));
+        assert!(matches!(codec.decode(&mut src), Err(CodecError::Header(_))));
+        assert!(matches!(codec.decode(&mut src), Err(CodecError::Json(_))));
+        // Still in sync afterwards
+        assert!(matches!(
+            codec.decode(&mut src).unwrap(),
+            Some(Message::Event(_))
+        ));
+
+        let mut garbage = BytesMut::from(vec![b'x'; MAX_HEADER + 1].as_slice());
+        assert!(matches!(
+            codec.decode(&mut garbage),
+            Err(CodecError::Header(_))
+        ));
+        assert!(garbage.is_empty());
+
+        // Too large a body is skipped as it comes in, without buffering it
+        let mut src = BytesMut::from(format!("Content-Length: {}\r\n\r\n", MAX_BODY + 1).as_str());
+        assert!(matches!(
+            codec.decode(&mut src),
+            Err(CodecError::TooLarge(length)) if length == MAX_BODY + 1
+        ));
+        src.extend_from_slice(&vec![b'x'; MAX_BODY]);
+        assert_eq!(codec.decode(&mut src).unwrap(), None);
+        assert!(src.is_empty());
+        src.extend_from_slice(format!("x{}", frame(INITIALIZED)).as_bytes());
+        assert!(matches!(
+            codec.decode(&mut src).unwrap(),
+            Some(Message::Event(_))
+        ));
+        let mut huge = BytesMut::from("Content-Length: 99999999999999\r\n\r\n");
+        assert!(matches!(
+            DapCodec::new().decode(&mut huge),
+            Err(CodecError::TooLarge(_))
+        ));
+    }
+
+    #[test]
+    fn test_encode() {
+        let mut codec = DapCodec::new();
+        let mut dst = BytesMut::new();
+        let event: Message = serde_json::from_str(INITIALIZED).unwrap();
+        codec.encode(event.clone(), &mut dst).unwrap();
+        let encoded = std::str::from_utf8(&dst).unwrap();
+        assert!(encoded.starts_with("Content-Length: "));
+        assert_eq!(codec.decode(&mut dst).unwrap(), Some(event));
+    }
+
+    #[test]
+    fn test_content_length_bytes() {
+        // Lengths count bytes, not characters
+        let body = r#"{"seq":4,"type":"event","event":"output","body":{"output":"héllo"}}"#;
+        let mut codec = DapCodec::new();
+        let mut src = BytesMut::from(frame(body).as_str());
+        assert!(matches!(
+            codec.decode(&mut src).unwrap(),
+            Some(Message::Event(_))
+        ));
+    }
+}
@@ -0,0 +1,278 @@
+use std::{env, fmt,
+          fs::{self, OpenOptions},
+          io,
+          os::unix::fs::OpenOptionsExt,
+          path::Path,
+          process,
+          sync::atomic::{AtomicU64, Ordering},
+          time::Duration};
+
+use rust_mi::{commands::MiCommand, types::Error, MIController, StopMode};
+use serde::Deserialize;
+
+use super::{sender::{DapSender, RequestError},
+            types::{RunInTerminalRequestArguments, RunInTerminalResponseBody}};
+
+// How long the client's terminal gets to tell which tty it is
+const TTY_TIMEOUT: Duration = Duration::from_secs(10);
+const TTY_POLL: Duration = Duration::from_millis(50);
+
+// Keeps the tty files of concurrent sessions apart
+static TTY_FILES: AtomicU64 = AtomicU64::new(0);
+
+/// Arguments of the `launch` request, the protocol leaves all but
+/// `noDebug` and `__restart` to the adapter.
+#[derive(Clone, PartialEq, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LaunchArguments {
+    /// Data from a previous, restarted session.
+    #[serde(rename = "__restart")]
+    pub restart: Option<serde_json::Value>,
+    #[serde(default)]
+    pub no_debug: bool,
+    /// Executable to run.
+    pub program: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+    /// Working directory of the program.
+    pub cwd: Option<String>,
+    /// Stops at the program's entry point instead of running on.
+    #[serde(default)]
+    pub stop_on_entry: bool,
+    #[serde(default)]
+    pub console: Console,
+    /// Keeps the other threads running while one is stopped, GDB's non-stop
+    /// mode.
+    #[serde(default)]
+    pub non_stop: bool,
+}
+
+/// Where the program's input and output go.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Console {
+    /// The debug console, mixed in with GDB's output.
+    #[default]
+    #[serde(rename = "internalConsole")]
+    Internal,
+    /// A terminal inside the client.
+    IntegratedTerminal,
+    /// A terminal window of its own.
+    ExternalTerminal,
+}
+
+/// A terminal the client opened for the program.
+#[derive(Debug)]
+pub struct Terminal {
+    pub tty: String,
+    // What the client started for it, the shell keeping it open and the
+    // terminal's own shell
+    processes: Vec<i32>,
+}
+
+impl Terminal {
+    fn new(response: RunInTerminalResponseBody) -> Self {
+        let processes = [response.process_id, response.shell_process_id]
+            .into_iter()
+            .flatten()
+            // 0 and negative pids would signal whole process groups
+            .filter_map(|pid| i32::try_from(pid).ok().filter(|pid| *pid > 0))
+            .collect();
+        Terminal {
+            tty: String::new(),
+            processes,
+        }
+    }
+
+    /// Hangs up on the shells holding the terminal open, as closing it
+    /// would.
+    pub fn close(self) {
+        for pid in self.processes {
+            // SAFETY: kill only reads its arguments
+            unsafe { libc::kill(pid, libc::SIGHUP) };
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum TerminalError {
+    Request(RequestError),
+    Io(io::Error),
+    /// The terminal didn't say which tty it is in time.
+    Timeout,
+    /// What the terminal wrote isn't a tty, i.e. `not a tty`.
+    NotATty(String),
+}
+
+impl fmt::Display for TerminalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TerminalError::Request(e) => write!(f, "couldn't open a terminal: {}", e),
+            TerminalError::Io(e) => write!(f, "couldn't open a terminal: {}", e),
+            TerminalError::Timeout => write!(f, "the terminal didn't start in time"),
+            TerminalError::NotATty(output) => {
+                write!(f, "the terminal has no tty: {}", output.trim())
+            }
+        }
+    }
+}
+
+impl From<RequestError> for TerminalError {
+    fn from(e: RequestError) -> Self {
+        TerminalError::Request(e)
+    }
+}
+
+impl From<io::Error> for TerminalError {
+    fn from(e: io::Error) -> Self {
+        TerminalError::Io(e)
+    }
+}
+
+impl LaunchArguments {
+    /// Loads the program into `controller`, it's started by [`Self::run`]
+    /// once the client finished configuring breakpoints.
+    pub async fn load(&self, controller: &MIController) -> Result<(), Error> {
+        if self.non_stop {
+            controller.set_stop_mode(StopMode::NonStop).await?;
+        }
+        controller
+            .execute(MiCommand::file_exec_and_symbols(self.program.as_str()))
+            .await?;
+        if !self.args.is_empty() {
+            controller
+                .execute(MiCommand::exec_arguments(&self.args))
+                .await?;
+        }
+        // Only the program runs there, GDB stays where it is
+        if let Some(cwd) = &self.cwd {
+            controller
+                .execute(MiCommand::gdb_set("cwd", cwd.as_str()))
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Opens a terminal for the program through the client, its tty is for
+    /// `-inferior-tty-set`.
+    ///
+    /// The terminal runs a shell that writes its tty to a file and sleeps,
+    /// GDB then starts the program on that tty. The file is in this machine's
+    /// temporary directory, so only local clients can open one.
+    pub async fn open_terminal(&self, sender: &DapSender) -> Result<Terminal, TerminalError> {
+        let path = env::temp_dir().join(format!(
+            "simpledap-tty-{}-{}",
+            process::id(),
+            TTY_FILES.fetch_add(1, Ordering::Relaxed)
+        ));
+        // Created up front so nobody else gets to pick what it says
+        OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .mode(0o600)
+            .open(&path)?;
+        let tty = self.wait_for_tty(sender, &path).await;
+        let _ = fs::remove_file(&path);
+        tty
+    }
+
+    async fn wait_for_tty(
+        &self,
+        sender: &DapSender,
+        path: &Path,
+    ) -> Result<Terminal, TerminalError> {
+        let cwd = match &self.cwd {
+            Some(cwd) => cwd.clone(),
+            None => env::current_dir()?.to_string_lossy().into_owned(),
+        };
+        let arguments = RunInTerminalRequestArguments {
+            args: vec![
+                "/bin/sh".to_owned(),
+                "-c".to_owned(),
+                r#"tty > "$0" && exec sleep 2147483647"#.to_owned(),
+                path.to_string_lossy().into_owned(),
+            ],
+            args_can_be_interpreted_by_shell: None,
+            cwd,
+            env: None,
+            kind: Some(match self.console {
+                Console::ExternalTerminal => "external".to_owned(),
+                _ => "integrated".to_owned(),
+            }),
+            title: Some(self.program.clone()),
+        };
+        let mut terminal = Terminal::new(sender.run_in_terminal(arguments).await?);
+        let tty = async {
+            loop {
+                // Whole once it ends in a newline
+                let written = fs::read_to_string(path)?;
+                if written.ends_with('\n') {
+                    return Ok::<_, io::Error>(written);
+                }
+                tokio::time::sleep(TTY_POLL).await;
+            }
+        };
+        let error = match tokio::time::timeout(TTY_TIMEOUT, tty).await {
+            Ok(Ok(tty)) if tty.starts_with('/') => {
+                terminal.tty = tty.trim_end().to_owned();
+                return Ok(terminal);
+            }
+            Ok(Ok(tty)) => TerminalError::NotATty(tty),
+            Ok(Err(e)) => TerminalError::Io(e),
+            Err(_) => TerminalError::Timeout,
+        };
+        // Useless without a tty
+        terminal.close();
+        Err(error)
+    }
+
+    pub async fn run(&self, controller: &MIController) -> Result<(), Error> {
+        let mut run = MiCommand::exec_run();
+        if self.stop_on_entry {
+            run = run.option("--start");
+        }
+        controller.execute(run).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_mi::testing::FakeGdb;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_launch() {
+        let (fake, controller) = FakeGdb::controller().unwrap();
+        fake.on("-file-exec-and-symbols", ["^done"])
+            .on("-exec-arguments", ["^done"])
+            .on("-gdb-set", ["^done"])
+            .on("-exec-run", ["^running"]);
+        let arguments: LaunchArguments = serde_json::from_str(
+            r#"{"program": "./app", "args": ["-v", "a b"], "cwd": "/srv", "stopOnEntry": true}"#,
+        )
+        .unwrap();
+        arguments.load(&controller).await.unwrap();
+        arguments.run(&controller).await.unwrap();
+        fake.assert_received(&[
+            "-file-exec-and-symbols ./app",
+            "-exec-arguments -v \"a b\"",
+            "-gdb-set cwd /srv",
+            "-exec-run --start",
+        ]);
+
+        let (fake, controller) = FakeGdb::controller().unwrap();
+        fake.on("-gdb-set", ["^done"])
+            .on("-file-exec-and-symbols", ["^done"]);
+        let arguments: LaunchArguments =
+            serde_json::from_str(r#"{"program": "./app", "nonStop": true}"#).unwrap();
+        arguments.load(&controller).await.unwrap();
+        assert_eq!(controller.stop_mode(), StopMode::NonStop);
+        fake.assert_received(&[
+            "-gdb-set mi-async on",
+            "-gdb-set non-stop on",
+            "-file-exec-and-symbols ./app",
+        ]);
+    }
+}
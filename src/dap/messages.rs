@@ -0,0 +1,216 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{requests::Incoming, types::*};
+
+/// The adapter's answer to a request.
+///
+/// `seq` is left at 0 by the constructors, it's assigned when the response
+/// is sent.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename = "response")]
+pub struct Response<B = Value> {
+    pub seq: i64,
+    /// Sequence number of the request answered.
+    pub request_seq: i64,
+    pub success: bool,
+    /// The command answered.
+    pub command: String,
+    /// The error in short form when `success` is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<B>,
+}
+
+impl<B> Response<B> {
+    /// A successful response to `request` carrying `body`.
+    pub fn ok(request: &Incoming, body: B) -> Self {
+        Self::ack(request).with_body(body)
+    }
+
+    /// A successful response to `request` without a body.
+    pub fn ack(request: &Incoming) -> Self {
+        Response {
+            seq: 0,
+            request_seq: request.seq,
+            success: true,
+            command: request.command().to_owned(),
+            message: None,
+            body: None,
+        }
+    }
+
+    fn with_body(mut self, body: B) -> Self {
+        self.body = Some(body);
+        self
+    }
+}
+
+impl Response<ErrorResponseBody> {
+    /// A failed response to `request`, `error.format` is shown to the user.
+    pub fn error(request: &Incoming, error: Message) -> Self {
+        Response {
+            success: false,
+            message: Some(error.format.clone()),
+            ..Self::ack(request).with_body(ErrorResponseBody { error: Some(error) })
+        }
+    }
+
+    /// A failed response to `request` that only carries the short form
+    /// `message`, i.e. `cancelled`.
+    pub fn failed(request: &Incoming, message: &str) -> Self {
+        Response {
+            success: false,
+            message: Some(message.to_owned()),
+            ..Self::ack(request)
+        }
+    }
+}
+
+/// Something the adapter tells the client without being asked.
+///
+/// Like [`Response`], `seq` is assigned when the event is sent.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename = "event")]
+pub struct Event<B = Value> {
+    pub seq: i64,
+    pub event: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<B>,
+}
+
+/// Ties an event body to the name of its event.
+pub trait EventBody {
+    const EVENT: &'static str;
+}
+
+macro_rules! event_bodies {
+    ($($body:ty = $event:literal,)*) => {
+        $(impl EventBody for $body {
+            const EVENT: &'static str = $event;
+        })*
+    };
+}
+
+event_bodies! {
+    BreakpointEventBody = "breakpoint",
+    CapabilitiesEventBody = "capabilities",
+    ContinuedEventBody = "continued",
+    ExitedEventBody = "exited",
+    InvalidatedEventBody = "invalidated",
+    LoadedSourceEventBody = "loadedSource",
+    MemoryEventBody = "memory",
+    ModuleEventBody = "module",
+    OutputEventBody = "output",
+    ProcessEventBody = "process",
+    ProgressEndEventBody = "progressEnd",
+    ProgressStartEventBody = "progressStart",
+    ProgressUpdateEventBody = "progressUpdate",
+    StoppedEventBody = "stopped",
+    TerminatedEventBody = "terminated",
+    ThreadEventBody = "thread",
+}
+
+impl<B: EventBody> Event<B> {
+    pub fn new(body: B) -> Self {
+        Event {
+            seq: 0,
+            event: B::EVENT.to_owned(),
+            body: Some(body),
+        }
+    }
+}
+
+impl Event {
+    /// An event without a body, i.e. `initialized`.
+    pub fn named(event: &str) -> Self {
+        Event {
+            seq: 0,
+            event: event.to_owned(),
+            body: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::dap::requests::IncomingRequest;
+
+    fn request(seq: i64, command: &str) -> Incoming {
+        Incoming {
+            seq,
+            request: IncomingRequest::parse(command.to_owned(), None).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_response() {
+        let threads = request(4, "threads");
+        let response = Response::ok(&threads, ThreadsResponseBody { threads: vec![] });
+        assert_eq!(
+            serde_json::to_value(&response).unwrap(),
+            json!({
+                "type": "response",
+                "seq": 0,
+                "request_seq": 4,
+                "success": true,
+                "command": "threads",
+                "body": {"threads": []}
+            })
+        );
+        let done = request(5, "configurationDone");
+        let value = serde_json::to_value(Response::<Value>::ack(&done)).unwrap();
+        assert_eq!(value["command"], "configurationDone");
+        assert!(value.get("body").is_none());
+
+        // Reads back what it wrote
+        let parsed: Response =
+            serde_json::from_value(serde_json::to_value(&response).unwrap()).unwrap();
+        assert_eq!(parsed.body, Some(json!({"threads": []})));
+    }
+
+    #[test]
+    fn test_error_response() {
+        let cancel = request(9, "cancel");
+        let error = Message {
+            format: "no such thread".to_owned(),
+            id: 1,
+            send_telemetry: None,
+            show_user: Some(true),
+            url: None,
+            url_label: None,
+            variables: None,
+        };
+        let response = Response::error(&cancel, error);
+        assert!(!response.success);
+        assert_eq!(response.request_seq, 9);
+        assert_eq!(response.command, "cancel");
+        assert_eq!(response.message.as_deref(), Some("no such thread"));
+        assert_eq!(response.body.unwrap().error.unwrap().id, 1);
+    }
+
+    #[test]
+    fn test_event() {
+        let event = Event::new(ThreadEventBody {
+            reason: "started".to_owned(),
+            thread_id: 1,
+        });
+        assert_eq!(
+            serde_json::to_value(&event).unwrap(),
+            json!({
+                "type": "event",
+                "seq": 0,
+                "event": "thread",
+                "body": {"reason": "started", "threadId": 1}
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(Event::named("initialized")).unwrap(),
+            json!({"type": "event", "seq": 0, "event": "initialized"})
+        );
+    }
+}
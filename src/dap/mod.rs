@@ -1 +1,13 @@
-pub (crate) mod types;
+pub(crate) mod attach;
+pub(crate) mod client;
+pub(crate) mod codec;
+pub(crate) mod launch;
+// Not every constructor has a handler using it yet
+#[allow(dead_code)]
+pub(crate) mod messages;
+pub(crate) mod requests;
+pub(crate) mod sender;
+pub(crate) mod session;
+// Generated from the DAP schema, most of it isn't wired up yet
+#[allow(dead_code)]
+pub(crate) mod types;
@@ -0,0 +1,241 @@
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+
+use super::{attach::AttachArguments, launch::LaunchArguments, types::*};
+
+// Variants with arguments are parsed from `arguments`, a missing
+// `arguments` is `null` so optional ones come out as `None`
+macro_rules! requests {
+    ($($variant:ident$(($arguments:ty))? = $command:literal,)*) => {
+        /// A request from the client, its arguments parsed according to
+        /// `command`.
+        #[derive(Clone, PartialEq, Debug)]
+        pub enum IncomingRequest {
+            $($variant$(($arguments))?,)*
+            /// A command this adapter doesn't know about.
+            Unknown {
+                command: String,
+                arguments: Option<Value>,
+            },
+            /// A known command whose arguments didn't parse.
+            Invalid {
+                command: String,
+                error: String,
+            },
+        }
+
+        impl IncomingRequest {
+            pub fn parse(command: String, arguments: Option<Value>) -> serde_json::Result<Self> {
+                let arguments = arguments.unwrap_or(Value::Null);
+                Ok(match command.as_str() {
+                    $($command => requests!(@parse $variant arguments $($arguments)?),)*
+                    _ => IncomingRequest::Unknown {
+                        command,
+                        arguments: Some(arguments).filter(|a| !a.is_null()),
+                    },
+                })
+            }
+
+            /// The `command` the request was sent with.
+            pub fn command(&self) -> &str {
+                match self {
+                    $(IncomingRequest::$variant { .. } => $command,)*
+                    IncomingRequest::Unknown { command, .. }
+                    | IncomingRequest::Invalid { command, .. } => command,
+                }
+            }
+        }
+    };
+    (@parse $variant:ident $value:ident $arguments:ty) => {
+        IncomingRequest::$variant(serde_json::from_value::<$arguments>($value)?)
+    };
+    (@parse $variant:ident $value:ident) => {
+        IncomingRequest::$variant
+    };
+}
+
+requests! {
+    Attach(AttachArguments) = "attach",
+    BreakpointLocations(Option<BreakpointLocationsArguments>) = "breakpointLocations",
+    Cancel(Option<CancelArguments>) = "cancel",
+    Completions(CompletionsArguments) = "completions",
+    ConfigurationDone(Option<ConfigurationDoneArguments>) = "configurationDone",
+    Continue(ContinueArguments) = "continue",
+    DataBreakpointInfo(DataBreakpointInfoArguments) = "dataBreakpointInfo",
+    Disassemble(DisassembleArguments) = "disassemble",
+    Disconnect(Option<DisconnectArguments>) = "disconnect",
+    Evaluate(EvaluateArguments) = "evaluate",
+    ExceptionInfo(ExceptionInfoArguments) = "exceptionInfo",
+    Goto(GotoArguments) = "goto",
+    GotoTargets(GotoTargetsArguments) = "gotoTargets",
+    Initialize(InitializeRequestArguments) = "initialize",
+    Launch(LaunchArguments) = "launch",
+    LoadedSources(Option<LoadedSourcesArguments>) = "loadedSources",
+    Locations(LocationsArguments) = "locations",
+    Modules(ModulesArguments) = "modules",
+    Next(NextArguments) = "next",
+    Pause(PauseArguments) = "pause",
+    ReadMemory(ReadMemoryArguments) = "readMemory",
+    RestartFrame(RestartFrameArguments) = "restartFrame",
+    Restart(Option<RestartArguments>) = "restart",
+    ReverseContinue(ReverseContinueArguments) = "reverseContinue",
+    Scopes(ScopesArguments) = "scopes",
+    SetBreakpoints(SetBreakpointsArguments) = "setBreakpoints",
+    SetDataBreakpoints(SetDataBreakpointsArguments) = "setDataBreakpoints",
+    SetExceptionBreakpoints(SetExceptionBreakpointsArguments) = "setExceptionBreakpoints",
+    SetExpression(SetExpressionArguments) = "setExpression",
+    SetFunctionBreakpoints(SetFunctionBreakpointsArguments) = "setFunctionBreakpoints",
+    SetInstructionBreakpoints(SetInstructionBreakpointsArguments) = "setInstructionBreakpoints",
+    SetVariable(SetVariableArguments) = "setVariable",
+    Source(SourceArguments) = "source",
+    StackTrace(StackTraceArguments) = "stackTrace",
+    StepBack(StepBackArguments) = "stepBack",
+    StepIn(StepInArguments) = "stepIn",
+    StepInTargets(StepInTargetsArguments) = "stepInTargets",
+    StepOut(StepOutArguments) = "stepOut",
+    Terminate(Option<TerminateArguments>) = "terminate",
+    TerminateThreads(TerminateThreadsArguments) = "terminateThreads",
+    Threads = "threads",
+    Variables(VariablesArguments) = "variables",
+    WriteMemory(WriteMemoryArguments) = "writeMemory",
+}
+
+/// A request as it arrived, see [`IncomingRequest`] for what it asks for.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Incoming {
+    pub seq: i64,
+    pub request: IncomingRequest,
+}
+
+impl Incoming {
+    pub fn command(&self) -> &str {
+        self.request.command()
+    }
+}
+
+impl<'de> Deserialize<'de> for Incoming {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Raw {
+            seq: i64,
+            command: String,
+            arguments: Option<Value>,
+        }
+
+        // Bad arguments still make a request, so it can be answered
+        let raw = Raw::deserialize(deserializer)?;
+        let request =
+            IncomingRequest::parse(raw.command.clone(), raw.arguments).unwrap_or_else(|e| {
+                IncomingRequest::Invalid {
+                    command: raw.command,
+                    error: e.to_string(),
+                }
+            });
+        Ok(Incoming {
+            seq: raw.seq,
+            request,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(json: &str) -> serde_json::Result<Incoming> {
+        serde_json::from_str(json)
+    }
+
+    #[test]
+    fn test_parse() {
+        let incoming = parse(
+            r#"{"seq":1,"type":"request","command":"initialize","arguments":{"adapterID":"gdb","linesStartAt1":true}}"#,
+        )
+        .unwrap();
+        assert_eq!(incoming.seq, 1);
+        assert_eq!(incoming.command(), "initialize");
+        match incoming.request {
+            IncomingRequest::Initialize(arguments) => {
+                assert_eq!(arguments.adapter_id, "gdb");
+                assert_eq!(arguments.lines_start_at_1, Some(true));
+            }
+            other => panic!("expected initialize, got {:?}", other),
+        }
+
+        let incoming = parse(
+            r#"{"seq":2,"type":"request","command":"setBreakpoints","arguments":{"source":{"path":"main.c"},"breakpoints":[{"line":12}]}}"#,
+        )
+        .unwrap();
+        match incoming.request {
+            IncomingRequest::SetBreakpoints(arguments) => {
+                assert_eq!(arguments.source.path.as_deref(), Some("main.c"));
+                assert_eq!(arguments.breakpoints.unwrap()[0].line, 12);
+            }
+            other => panic!("expected setBreakpoints, got {:?}", other),
+        }
+
+        let incoming = parse(
+            r#"{"seq":3,"type":"request","command":"attach","arguments":{"program":"./app","coreFile":"core"}}"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            incoming.request,
+            IncomingRequest::Attach(AttachArguments {
+                core_file: Some(_),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_newer_protocol() {
+        let incoming = parse(
+            r#"{"seq":1,"type":"request","command":"initialize","arguments":{"adapterID":"gdb","supportsANSIStyling":true,"supportsStartDebuggingRequest":true}}"#,
+        )
+        .unwrap();
+        match incoming.request {
+            IncomingRequest::Initialize(arguments) => {
+                assert_eq!(arguments.supports_ansi_styling, Some(true));
+                assert_eq!(arguments.supports_start_debugging_request, Some(true));
+            }
+            other => panic!("expected initialize, got {:?}", other),
+        }
+        let incoming = parse(
+            r#"{"seq":2,"type":"request","command":"locations","arguments":{"locationReference":3}}"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            incoming.request,
+            IncomingRequest::Locations(LocationsArguments {
+                location_reference: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn test_missing_arguments() {
+        let threads = parse(r#"{"seq":4,"type":"request","command":"threads"}"#).unwrap();
+        assert_eq!(threads.request, IncomingRequest::Threads);
+        let done = parse(r#"{"seq":5,"type":"request","command":"configurationDone"}"#).unwrap();
+        assert_eq!(done.request, IncomingRequest::ConfigurationDone(None));
+        // Required ones can't be left out
+        let next = parse(r#"{"seq":6,"type":"request","command":"next"}"#).unwrap();
+        assert_eq!(next.command(), "next");
+        assert!(matches!(next.request, IncomingRequest::Invalid { .. }));
+    }
+
+    #[test]
+    fn test_unknown() {
+        let incoming =
+            parse(r#"{"seq":7,"type":"request","command":"fancyNewThing","arguments":{"x":1}}"#)
+                .unwrap();
+        assert_eq!(incoming.command(), "fancyNewThing");
+        assert_eq!(
+            incoming.request,
+            IncomingRequest::Unknown {
+                command: "fancyNewThing".to_owned(),
+                arguments: Some(serde_json::json!({"x": 1})),
+            }
+        );
+    }
+}
@@ -0,0 +1,341 @@
+use std::{collections::HashMap,
+          fmt, io,
+          sync::{Arc, Mutex}};
+
+use bytes::BytesMut;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use tokio::{io::{AsyncWrite, AsyncWriteExt},
+            sync::{mpsc, oneshot},
+            task::JoinHandle};
+use tokio_util::codec::Encoder;
+
+use super::{codec::{CodecError, DapCodec},
+            messages::Response,
+            types::{Request, RunInTerminalRequestArguments, RunInTerminalResponseBody}};
+
+/// Why a reverse request didn't get a useful answer.
+#[derive(Debug)]
+pub enum RequestError {
+    Send(CodecError),
+    /// The session ended before the client answered.
+    Closed,
+    /// The client answered with `success: false` and this message.
+    Failed(Option<String>),
+    /// The response body isn't what the request should get.
+    Body(serde_json::Error),
+}
+
+impl fmt::Display for RequestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RequestError::Send(e) => write!(f, "{}", e),
+            RequestError::Closed => write!(f, "the client went away before answering"),
+            RequestError::Failed(Some(message)) => write!(f, "the client refused: {}", message),
+            RequestError::Failed(None) => write!(f, "the client refused"),
+            RequestError::Body(e) => write!(f, "unexpected response body: {}", e),
+        }
+    }
+}
+
+impl From<CodecError> for RequestError {
+    fn from(e: CodecError) -> Self {
+        RequestError::Send(e)
+    }
+}
+
+type Pending = HashMap<i64, oneshot::Sender<Response>>;
+
+/// Sends responses, events and reverse requests to the client.
+///
+/// Every message gets the next `seq`, in the order it's written out, and
+/// is written by a single task so clones can be used from anywhere.
+#[derive(Clone, Debug)]
+pub struct DapSender {
+    // The last `seq` handed out, held while queueing so seq and queue order
+    // agree
+    queue: Arc<Mutex<(i64, mpsc::UnboundedSender<Value>)>>,
+    // Reverse requests waiting for the client, by `seq`
+    pending: Arc<Mutex<Pending>>,
+}
+
+impl DapSender {
+    /// Starts the writer task, it ends once every sender is dropped or
+    /// writing fails.
+    pub fn spawn<W>(writer: W) -> (DapSender, JoinHandle<io::Result<()>>)
+    where
+        W: AsyncWrite + Unpin + Send + 'static,
+    {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let writer = tokio::spawn(write_loop(writer, rx));
+        let sender = DapSender {
+            queue: Arc::new(Mutex::new((0, tx))),
+            pending: Arc::default(),
+        };
+        (sender, writer)
+    }
+
+    /// Queues `message` with its `seq` filled in and returns that `seq`.
+    pub fn send<M: Serialize>(&self, message: M) -> Result<i64, CodecError> {
+        self.queue(message, None)
+    }
+
+    fn queue<M: Serialize>(
+        &self,
+        message: M,
+        reply: Option<oneshot::Sender<Response>>,
+    ) -> Result<i64, CodecError> {
+        let mut message = serde_json::to_value(message).map_err(CodecError::Json)?;
+        let mut queue = self.queue.lock().unwrap();
+        let seq = queue.0 + 1;
+        if let Some(object) = message.as_object_mut() {
+            object.insert("seq".to_owned(), seq.into());
+        }
+        // Registered first, the answer can't be read before it's written
+        if let Some(reply) = reply {
+            self.pending.lock().unwrap().insert(seq, reply);
+        }
+        if queue.1.send(message).is_err() {
+            self.pending.lock().unwrap().remove(&seq);
+            return Err(io::Error::new(io::ErrorKind::BrokenPipe, "dap writer stopped").into());
+        }
+        queue.0 = seq;
+        Ok(seq)
+    }
+
+    /// Sends `command` to the client and waits for its answer.
+    pub async fn request<A: Serialize>(
+        &self,
+        command: &str,
+        arguments: A,
+    ) -> Result<Response, RequestError> {
+        let request = Request {
+            arguments: Some(serde_json::to_value(arguments).map_err(CodecError::Json)?),
+            command: command.to_owned(),
+            seq: 0,
+            type_: "request".to_owned(),
+        };
+        let (tx, rx) = oneshot::channel();
+        self.queue(request, Some(tx))?;
+        let response = rx.await.map_err(|_| RequestError::Closed)?;
+        if response.success {
+            Ok(response)
+        } else {
+            Err(RequestError::Failed(response.message))
+        }
+    }
+
+    /// Asks the client to run a command in its terminal, for programs that
+    /// need one.
+    pub async fn run_in_terminal(
+        &self,
+        arguments: RunInTerminalRequestArguments,
+    ) -> Result<RunInTerminalResponseBody, RequestError> {
+        let response = self.request("runInTerminal", arguments).await?;
+        body(response)
+    }
+
+    /// Hands a response from the client to the reverse request waiting for
+    /// it, returns false if nothing was.
+    pub fn resolve(&self, response: Response) -> bool {
+        let reply = self.pending.lock().unwrap().remove(&response.request_seq);
+        match reply {
+            Some(reply) => reply.send(response).is_ok(),
+            None => false,
+        }
+    }
+
+    /// Fails every reverse request still waiting with
+    /// [`RequestError::Closed`], once the client is gone.
+    pub fn cancel_requests(&self) {
+        self.pending.lock().unwrap().clear();
+    }
+}
+
+fn body<B: DeserializeOwned>(response: Response) -> Result<B, RequestError> {
+    serde_json::from_value(response.body.unwrap_or(Value::Null)).map_err(RequestError::Body)
+}
+
+async fn write_loop<W>(mut writer: W, mut rx: mpsc::UnboundedReceiver<Value>) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let mut codec = DapCodec::new();
+    let mut buf = BytesMut::new();
+    while let Some(message) = rx.recv().await {
+        encode(&mut codec, message, &mut buf)?;
+        // Batch whatever else is already queued into the same write
+        while let Ok(message) = rx.try_recv() {
+            encode(&mut codec, message, &mut buf)?;
+        }
+        writer.write_all(&buf).await?;
+        writer.flush().await?;
+        buf.clear();
+    }
+    Ok(())
+}
+
+fn encode(codec: &mut DapCodec, message: Value, buf: &mut BytesMut) -> io::Result<()> {
+    codec.encode(message, buf).map_err(|e| match e {
+        CodecError::Io(e) => e,
+        e => io::Error::new(io::ErrorKind::InvalidData, e.to_string()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::io::AsyncReadExt;
+    use tokio_util::codec::Decoder;
+
+    use super::*;
+    use crate::dap::{codec::Message, messages::Event, types::OutputEventBody};
+
+    fn output(text: &str) -> Event<OutputEventBody> {
+        let body: OutputEventBody =
+            serde_json::from_value(serde_json::json!({ "output": text })).unwrap();
+        Event::new(body)
+    }
+
+    #[tokio::test]
+    async fn test_sequence() {
+        let (client, adapter) = tokio::io::duplex(64);
+        let (sender, writer) = DapSender::spawn(adapter);
+        let tasks: Vec<_> = (0..4)
+            .map(|task| {
+                let sender = sender.clone();
+                tokio::spawn(async move {
+                    for i in 0..10 {
+                        sender.send(output(&format!("{} {}", task, i))).unwrap();
+                        tokio::task::yield_now().await;
+                    }
+                })
+            })
+            .collect();
+        for task in tasks {
+            task.await.unwrap();
+        }
+        assert_eq!(sender.send(Event::named("terminated")).unwrap(), 41);
+        drop(sender);
+
+        let mut bytes = Vec::new();
+        let (read, written) = tokio::join!(
+            async {
+                let mut client = client;
+                client.read_to_end(&mut bytes).await
+            },
+            writer
+        );
+        read.unwrap();
+        written.unwrap().unwrap();
+
+        let mut src = BytesMut::from(bytes.as_slice());
+        let mut codec = DapCodec::new();
+        let mut seqs = Vec::new();
+        while let Some(message) = codec.decode(&mut src).unwrap() {
+            match message {
+                Message::Event(event) => seqs.push(event.seq),
+                other => panic!("expected an event, got {:?}", other),
+            }
+        }
+        assert_eq!(seqs, (1..=41).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn test_closed() {
+        let (client, adapter) = tokio::io::duplex(64);
+        let (sender, writer) = DapSender::spawn(adapter);
+        drop(client);
+        sender.send(Event::named("initialized")).unwrap();
+        assert!(writer.await.unwrap().is_err());
+        assert!(matches!(
+            sender.send(Event::named("initialized")),
+            Err(CodecError::Io(_))
+        ));
+    }
+
+    // Answers requests the way a client would, through the codec
+    async fn client(stream: tokio::io::DuplexStream, sender: DapSender, answer: Value) {
+        let mut src = BytesMut::new();
+        let mut codec = DapCodec::new();
+        let mut stream = stream;
+        loop {
+            let request = loop {
+                if let Some(Message::Request(request)) = codec.decode(&mut src).unwrap() {
+                    break request;
+                }
+                if stream.read_buf(&mut src).await.unwrap() == 0 {
+                    return;
+                }
+            };
+            let mut response: Response = serde_json::from_value(answer.clone()).unwrap();
+            response.request_seq = request.seq;
+            response.command = request.command().to_owned();
+            assert!(sender.resolve(response));
+        }
+    }
+
+    fn terminal() -> RunInTerminalRequestArguments {
+        serde_json::from_value(serde_json::json!({
+            "cwd": "/tmp",
+            "args": ["./app", "--verbose"],
+            "kind": "integrated"
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_run_in_terminal() {
+        let (client_end, adapter) = tokio::io::duplex(1024);
+        let (sender, _writer) = DapSender::spawn(adapter);
+        tokio::spawn(client(
+            client_end,
+            sender.clone(),
+            serde_json::json!({
+                "type": "response", "seq": 1, "request_seq": 0, "success": true,
+                "command": "", "body": {"processId": 4321}
+            }),
+        ));
+        sender.send(Event::named("initialized")).unwrap();
+        let body = sender.run_in_terminal(terminal()).await.unwrap();
+        assert_eq!(body.process_id, Some(4321));
+        // Unknown or already answered responses are ignored
+        let stray: Response = serde_json::from_value(serde_json::json!({
+            "type": "response", "seq": 9, "request_seq": 2, "success": true, "command": "runInTerminal"
+        }))
+        .unwrap();
+        assert!(!sender.resolve(stray));
+    }
+
+    #[tokio::test]
+    async fn test_request_failed() {
+        let (client_end, adapter) = tokio::io::duplex(1024);
+        let (sender, _writer) = DapSender::spawn(adapter);
+        tokio::spawn(client(
+            client_end,
+            sender.clone(),
+            serde_json::json!({
+                "type": "response", "seq": 1, "request_seq": 0, "success": false,
+                "command": "", "message": "no terminal"
+            }),
+        ));
+        assert!(matches!(
+            sender.run_in_terminal(terminal()).await,
+            Err(RequestError::Failed(Some(message))) if message == "no terminal"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_request_cancelled() {
+        let (_client_end, adapter) = tokio::io::duplex(1024);
+        let (sender, _writer) = DapSender::spawn(adapter);
+        let request = tokio::spawn({
+            let sender = sender.clone();
+            async move { sender.run_in_terminal(terminal()).await }
+        });
+        while sender.pending.lock().unwrap().is_empty() {
+            tokio::task::yield_now().await;
+        }
+        sender.cancel_requests();
+        assert!(matches!(request.await.unwrap(), Err(RequestError::Closed)));
+    }
+}
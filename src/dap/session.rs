@@ -0,0 +1,1338 @@
+use std::{collections::HashMap,
+          fmt, io,
+          sync::{Arc, Mutex},
+          time::Duration};
+
+use bytes::BytesMut;
+use rust_mi::{commands::MiCommand,
+              parser::output_types::{AsyncOutput, OutputClass, OutputData, StreamOutput, Value,
+                                     OOB},
+              pty::PtyReader,
+              CancelToken, Event as MiEvent, ExecuteOptions, MIController, StopMode, WhileRunning};
+use tokio::{io::{AsyncRead, AsyncReadExt, AsyncWrite},
+            sync::{broadcast, mpsc},
+            task::JoinHandle};
+use tokio_util::codec::Decoder;
+
+use super::{attach::AttachArguments,
+            client::ClientCapabilities,
+            codec::{CodecError, DapCodec, Message as DapMessage},
+            launch::{Console, LaunchArguments, Terminal},
+            messages::{Event, Response},
+            requests::{Incoming, IncomingRequest},
+            sender::DapSender,
+            types::*};
+
+// How long GDB gets to quit once the session is over
+const EXIT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Serves one client talking DAP over `reader` and `writer`, debugging
+/// with `controller`, until the client disconnects or goes away.
+///
+/// Only a `local` client, on this machine, can give the program a terminal.
+pub async fn serve<R, W>(
+    reader: R,
+    writer: W,
+    controller: MIController,
+    local: bool,
+) -> io::Result<()>
+where
+    R: AsyncRead + Unpin + Send + 'static,
+    W: AsyncWrite + Unpin + Send + 'static,
+{
+    let (sender, writer) = DapSender::spawn(writer);
+    // Read separately so responses to reverse requests get through while a
+    // handler waits for them
+    let (requests_tx, mut requests) = mpsc::unbounded_channel();
+    let cancels = Cancels::default();
+    let reader = tokio::spawn(read_loop(
+        reader,
+        sender.clone(),
+        requests_tx,
+        cancels.clone(),
+    ));
+    let mut session = Session::new(sender.clone(), controller, local, cancels);
+    while let Some(incoming) = requests.recv().await {
+        if !session.handle(incoming).await {
+            break;
+        }
+    }
+    sender.cancel_requests();
+    session.shutdown().await;
+    // The writer finishes once the last sender is gone
+    drop(session);
+    drop(sender);
+    // Nothing to read after a `disconnect`, a finished reader keeps its result
+    reader.abort();
+    let read = match reader.await {
+        Ok(read) => read,
+        Err(_) => Ok(()),
+    };
+    let written = writer.await.unwrap_or_else(|e| Err(io::Error::other(e)));
+    read.and(written)
+}
+
+/// The cancel tokens of the requests not answered yet, by `seq`.
+type Cancels = Arc<Mutex<HashMap<i64, CancelToken>>>;
+
+async fn read_loop<R>(
+    mut reader: R,
+    sender: DapSender,
+    requests: mpsc::UnboundedSender<Incoming>,
+    cancels: Cancels,
+) -> io::Result<()>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut codec = DapCodec::new();
+    let mut buf = BytesMut::new();
+    loop {
+        match codec.decode(&mut buf) {
+            // Answered here, the session is busy with what it cancels
+            Ok(Some(DapMessage::Request(request)))
+                if matches!(request.request, IncomingRequest::Cancel(_)) =>
+            {
+                if let IncomingRequest::Cancel(Some(CancelArguments {
+                    request_id: Some(id),
+                    ..
+                })) = &request.request
+                {
+                    // Already answered requests are left alone
+                    if let Some(cancel) = cancels.lock().unwrap().get(id) {
+                        cancel.cancel();
+                    }
+                }
+                let _ = sender.send(Response::<()>::ack(&request));
+            }
+            Ok(Some(DapMessage::Request(request))) => {
+                cancels
+                    .lock()
+                    .unwrap()
+                    .insert(request.seq, CancelToken::new());
+                if requests.send(request).is_err() {
+                    return Ok(());
+                }
+            }
+            Ok(Some(DapMessage::Response(response))) => {
+                sender.resolve(response);
+            }
+            // Clients don't send events
+            Ok(Some(DapMessage::Event(_))) => {}
+            Ok(None) => {
+                if reader.read_buf(&mut buf).await? == 0 {
+                    return Ok(());
+                }
+            }
+            Err(CodecError::Io(e)) => return Err(e),
+            Err(e) => eprintln!("simpledap: dropping message: {}", e),
+        }
+    }
+}
+
+struct Session {
+    sender: DapSender,
+    controller: Option<MIController>,
+    local: bool,
+    cancels: Cancels,
+    // Cancels the request being handled
+    cancel: CancelToken,
+    // Defaults until `initialize` says otherwise
+    client: ClientCapabilities,
+    launch: Option<LaunchArguments>,
+    // Announced once the client is done configuring, for attach sessions
+    stopped: Option<StoppedEventBody>,
+    events: Option<JoinHandle<()>>,
+    // Turns what the program prints into `output` events
+    inferior_output: Option<JoinHandle<()>>,
+    // The client's terminal the program runs in, closed with the session
+    terminal: Option<Terminal>,
+    // Forgotten by `forward` whenever the target moves
+    frames: Arc<Mutex<Frames>>,
+}
+
+/// Hands out ids for the frames the client was shown, they only stay valid
+/// until the target runs or stops again.
+#[derive(Debug, Default)]
+struct Frames {
+    ids: HashMap<(u32, u32), i64>,
+    // Thread and level by id, the first id is 1
+    frames: Vec<(u32, u32)>,
+}
+
+impl Frames {
+    fn id(&mut self, thread: u32, level: u32) -> i64 {
+        let frames = &mut self.frames;
+        *self.ids.entry((thread, level)).or_insert_with(|| {
+            frames.push((thread, level));
+            frames.len() as i64
+        })
+    }
+
+    /// The thread and level of frame `id`.
+    fn get(&self, id: i64) -> Option<(u32, u32)> {
+        let index = usize::try_from(id).ok()?.checked_sub(1)?;
+        self.frames.get(index).copied()
+    }
+
+    fn clear(&mut self) {
+        self.ids.clear();
+        self.frames.clear();
+    }
+}
+
+type Handled = Result<(), Message>;
+
+fn error_message<E: fmt::Display>(error: E) -> Message {
+    Message {
+        format: error.to_string(),
+        id: 1,
+        send_telemetry: None,
+        show_user: Some(true),
+        url: None,
+        url_label: None,
+        variables: None,
+    }
+}
+
+impl Session {
+    fn new(sender: DapSender, controller: MIController, local: bool, cancels: Cancels) -> Self {
+        Session {
+            sender,
+            controller: Some(controller),
+            local,
+            cancels,
+            cancel: CancelToken::new(),
+            client: ClientCapabilities::default(),
+            launch: None,
+            stopped: None,
+            events: None,
+            inferior_output: None,
+            terminal: None,
+            frames: Arc::default(),
+        }
+    }
+
+    // Answers `incoming`, false once the session is over
+    async fn handle(&mut self, incoming: Incoming) -> bool {
+        let cancel = self.cancels.lock().unwrap().get(&incoming.seq).cloned();
+        self.cancel = cancel.unwrap_or_default();
+        if self.cancel.is_cancelled() {
+            // Before its turn came
+            self.reply(Response::failed(&incoming, "cancelled"));
+        } else if let Err(message) = self.dispatch(&incoming).await {
+            // Whatever failed, it was because of the cancel
+            if self.cancel.is_cancelled() {
+                self.reply(Response::failed(&incoming, "cancelled"));
+            } else {
+                self.reply(Response::error(&incoming, message));
+            }
+        }
+        self.cancels.lock().unwrap().remove(&incoming.seq);
+        !matches!(incoming.request, IncomingRequest::Disconnect(_))
+    }
+
+    fn reply<M: serde::Serialize>(&self, message: M) {
+        // Only fails once the client is gone, the read loop notices too
+        let _ = self.sender.send(message);
+    }
+
+    fn controller(&self) -> &MIController {
+        // Only taken away on shutdown
+        self.controller.as_ref().unwrap()
+    }
+
+    async fn execute(&self, command: MiCommand) -> Result<OutputData<'static>, Message> {
+        // Requests are handled one at a time, one waiting for the target to
+        // stop would hold up the `pause` that stops it
+        let options = ExecuteOptions::default()
+            .while_running(WhileRunning::Fail)
+            .cancel(self.cancel.clone());
+        self.controller()
+            .execute_with(command, options)
+            .await
+            .map_err(error_message)
+    }
+
+    async fn dispatch(&mut self, incoming: &Incoming) -> Handled {
+        match &incoming.request {
+            IncomingRequest::Initialize(arguments) => {
+                self.client = ClientCapabilities::from(arguments);
+                let capabilities = Capabilities {
+                    supports_cancel_request: Some(true),
+                    supports_configuration_done_request: Some(true),
+                    ..Default::default()
+                };
+                self.reply(Response::ok(incoming, capabilities));
+                self.reply(Event::named("initialized"));
+            }
+            IncomingRequest::Launch(arguments) => {
+                let arguments = LaunchArguments {
+                    program: self.client.gdb_path(&arguments.program),
+                    cwd: arguments
+                        .cwd
+                        .as_deref()
+                        .map(|cwd| self.client.gdb_path(cwd)),
+                    ..arguments.clone()
+                };
+                // Clients without terminals get the program's output in the
+                // debug console
+                let in_terminal =
+                    arguments.console != Console::Internal && self.client.run_in_terminal;
+                if in_terminal && !self.local {
+                    return Err(error_message(
+                        "a terminal can only be opened for clients on the same machine, use \
+                         \"console\": \"internalConsole\"",
+                    ));
+                }
+                let progress = self.start_progress(incoming, "Loading", &arguments.program);
+                let loaded = arguments.load(self.controller()).await;
+                self.end_progress(progress);
+                loaded.map_err(error_message)?;
+                if in_terminal {
+                    let terminal = arguments
+                        .open_terminal(&self.sender)
+                        .await
+                        .map_err(error_message)?;
+                    let tty = terminal.tty.clone();
+                    if let Some(previous) = self.terminal.replace(terminal) {
+                        previous.close();
+                    }
+                    self.execute(MiCommand::inferior_tty_set(tty)).await?;
+                } else {
+                    // On GDB's own terminal it would end up in the MI stream
+                    let (terminal, _) = self
+                        .controller()
+                        .open_inferior_tty()
+                        .await
+                        .map_err(error_message)?
+                        .split();
+                    let output = tokio::spawn(forward_output(terminal, self.sender.clone()));
+                    if let Some(previous) = self.inferior_output.replace(output) {
+                        previous.abort();
+                    }
+                }
+                self.launch = Some(arguments);
+                self.reply(Response::<()>::ack(incoming));
+            }
+            IncomingRequest::Attach(arguments) => {
+                let path = |path: &Option<String>| path.as_deref().map(|p| self.client.gdb_path(p));
+                let arguments = AttachArguments {
+                    program: path(&arguments.program),
+                    core_file: path(&arguments.core_file),
+                    ..arguments.clone()
+                };
+                let target = arguments.program.as_deref().unwrap_or("target");
+                let progress = self.start_progress(incoming, "Attaching to", target);
+                let attached = arguments.attach(self.controller()).await;
+                self.end_progress(progress);
+                let stopped = attached.map_err(error_message)?;
+                // GDB stops whatever it attaches to
+                self.stopped = Some(stopped.unwrap_or_else(|| StoppedEventBody {
+                    all_threads_stopped: Some(true),
+                    description: None,
+                    hit_breakpoint_ids: None,
+                    preserve_focus_hint: None,
+                    reason: "pause".to_owned(),
+                    text: None,
+                    thread_id: None,
+                }));
+                self.reply(Response::<()>::ack(incoming));
+            }
+            IncomingRequest::ConfigurationDone(_) => {
+                // Subscribed before running so nothing gets lost
+                let events = self.controller().events();
+                self.events = Some(tokio::spawn(forward(
+                    events,
+                    self.sender.clone(),
+                    self.frames.clone(),
+                )));
+                if let Some(launch) = &self.launch {
+                    launch.run(self.controller()).await.map_err(error_message)?;
+                }
+                self.reply(Response::<()>::ack(incoming));
+                if let Some(stopped) = self.stopped.take() {
+                    self.reply(Event::new(stopped));
+                }
+            }
+            IncomingRequest::Threads => {
+                let info = self.execute(MiCommand::thread_info()).await?;
+                let threads = info
+                    .get("threads")
+                    .map(Value::values)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(thread)
+                    .collect();
+                self.reply(Response::ok(incoming, ThreadsResponseBody { threads }));
+            }
+            IncomingRequest::StackTrace(arguments) => {
+                let frames = self
+                    .execute(MiCommand::stack_list_frames().thread(arguments.thread_id as u32))
+                    .await?;
+                let mut ids = self.frames.lock().unwrap();
+                let frames: Vec<_> = frames
+                    .get("stack")
+                    .map(Value::values)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|frame| {
+                        stack_frame(&self.client, &mut ids, arguments.thread_id as u32, frame)
+                    })
+                    .collect();
+                drop(ids);
+                let total = frames.len() as i64;
+                let start = arguments.start_frame.unwrap_or(0).max(0) as usize;
+                let levels = match arguments.levels {
+                    Some(levels) if levels > 0 => levels as usize,
+                    _ => frames.len(),
+                };
+                let stack_frames = frames.into_iter().skip(start).take(levels).collect();
+                self.reply(Response::ok(
+                    incoming,
+                    StackTraceResponseBody {
+                        stack_frames,
+                        total_frames: Some(total),
+                    },
+                ));
+            }
+            IncomingRequest::Scopes(arguments) => {
+                self.frame(arguments.frame_id)?;
+                // Arguments and locals come as one, listed by the frame
+                let locals = Scope {
+                    column: None,
+                    end_column: None,
+                    end_line: None,
+                    expensive: false,
+                    indexed_variables: None,
+                    line: None,
+                    name: "Locals".to_owned(),
+                    named_variables: None,
+                    presentation_hint: Some("locals".to_owned()),
+                    source: None,
+                    variables_reference: arguments.frame_id,
+                };
+                self.reply(Response::ok(
+                    incoming,
+                    ScopesResponseBody {
+                        scopes: vec![locals],
+                    },
+                ));
+            }
+            IncomingRequest::Variables(arguments) => {
+                let (thread, level) = self.frame(arguments.variables_reference)?;
+                let listed = self
+                    .execute(MiCommand::stack_list_variables(level).thread(thread))
+                    .await?;
+                let variables = listed
+                    .get("variables")
+                    .map(Value::values)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|variable| self.variable(variable))
+                    .collect();
+                self.reply(Response::ok(incoming, VariablesResponseBody { variables }));
+            }
+            IncomingRequest::Continue(arguments) => {
+                // Threads only go on their own in non-stop mode, where
+                // without `--all` only GDB's selected one would
+                let single_thread = self.non_stop() && arguments.single_thread == Some(true);
+                let mut command = MiCommand::exec_continue();
+                if single_thread {
+                    command = command.thread(arguments.thread_id as u32);
+                } else if self.non_stop() {
+                    command = command.option("--all");
+                }
+                self.execute(command).await?;
+                self.reply(Response::ok(
+                    incoming,
+                    ContinueResponseBody {
+                        all_threads_continued: Some(!single_thread),
+                    },
+                ));
+            }
+            IncomingRequest::Next(arguments) => {
+                let thread = (arguments.thread_id, arguments.single_thread);
+                self.step(incoming, MiCommand::exec_next(), thread).await?;
+            }
+            IncomingRequest::StepIn(arguments) => {
+                let thread = (arguments.thread_id, arguments.single_thread);
+                self.step(incoming, MiCommand::exec_step(), thread).await?;
+            }
+            IncomingRequest::StepOut(arguments) => {
+                let thread = (arguments.thread_id, arguments.single_thread);
+                self.step(incoming, MiCommand::exec_finish(), thread)
+                    .await?;
+            }
+            IncomingRequest::Pause(arguments) => {
+                let mut command = MiCommand::exec_interrupt();
+                if self.non_stop() {
+                    command = command.thread(arguments.thread_id as u32);
+                }
+                self.execute(command).await?;
+                self.reply(Response::<()>::ack(incoming));
+            }
+            IncomingRequest::Disconnect(_) => {
+                self.reply(Response::<()>::ack(incoming));
+            }
+            IncomingRequest::Invalid { error, .. } => {
+                return Err(error_message(format!("invalid arguments: {}", error)));
+            }
+            _ => {
+                return Err(error_message(format!(
+                    "{} isn't supported",
+                    incoming.command()
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn frame(&self, id: i64) -> Result<(u32, u32), Message> {
+        self.frames
+            .lock()
+            .unwrap()
+            .get(id)
+            .ok_or_else(|| error_message(format!("frame {} is gone, the program moved on", id)))
+    }
+
+    fn variable(&self, variable: &Value<'_>) -> Option<Variable> {
+        let text = |key| variable.get(key).and_then(Value::as_str).map(str::to_owned);
+        Some(Variable {
+            declaration_location_reference: None,
+            evaluate_name: text("name"),
+            indexed_variables: None,
+            memory_reference: None,
+            name: text("name")?,
+            named_variables: None,
+            presentation_hint: None,
+            type_: self.client.variable_type(text("type")),
+            // Structs, arrays and unions come without one
+            value: text("value").unwrap_or_else(|| "{...}".to_owned()),
+            value_location_reference: None,
+            variables_reference: 0,
+        })
+    }
+
+    fn non_stop(&self) -> bool {
+        self.controller().stop_mode() == StopMode::NonStop
+    }
+
+    // Steps `thread`, resuming the others too unless the client asked for
+    // `singleThread`. All-stop GDB resumes them along with it anyway.
+    async fn step(
+        &self,
+        incoming: &Incoming,
+        command: MiCommand,
+        (thread, single_thread): (i64, Option<bool>),
+    ) -> Handled {
+        let thread = thread as u32;
+        if self.non_stop() && single_thread != Some(true) {
+            // One by one and first, `--all` would also resume the stepped
+            // thread if its step is already over
+            let state = self.controller().state();
+            let others = state
+                .threads()
+                .map(|t| t.id)
+                .filter(|id| *id != thread && state.is_thread_stopped(*id));
+            for other in others {
+                self.execute(MiCommand::exec_continue().thread(other))
+                    .await?;
+            }
+        }
+        self.execute(command.thread(thread)).await?;
+        self.reply(Response::<()>::ack(incoming));
+        Ok(())
+    }
+
+    // Tells clients that show progress what a slow request is doing
+    fn start_progress(&self, incoming: &Incoming, action: &str, what: &str) -> Option<String> {
+        if !self.client.progress_reporting {
+            return None;
+        }
+        let progress_id = format!("{}-{}", incoming.command(), incoming.seq);
+        self.reply(Event::new(ProgressStartEventBody {
+            cancellable: Some(false),
+            message: None,
+            percentage: None,
+            progress_id: progress_id.clone(),
+            request_id: Some(incoming.seq),
+            title: format!("{} {}", action, what),
+        }));
+        Some(progress_id)
+    }
+
+    fn end_progress(&self, progress_id: Option<String>) {
+        if let Some(progress_id) = progress_id {
+            self.reply(Event::new(ProgressEndEventBody {
+                message: None,
+                progress_id,
+            }));
+        }
+    }
+
+    async fn shutdown(&mut self) {
+        for task in [self.events.take(), self.inferior_output.take()]
+            .into_iter()
+            .flatten()
+        {
+            task.abort();
+        }
+        if let Some(terminal) = self.terminal.take() {
+            terminal.close();
+        }
+        if let Some(controller) = self.controller.take() {
+            // Giving up drops the controller, which kills GDB
+            match tokio::time::timeout(EXIT_TIMEOUT, controller.exit()).await {
+                Ok(Ok(())) => {}
+                Ok(Err(e)) => eprintln!("simpledap: stopping gdb: {}", e),
+                Err(_) => eprintln!("simpledap: gdb didn't exit, killed it"),
+            }
+        }
+    }
+}
+
+fn number(value: Option<&Value<'_>>) -> Option<i64> {
+    value.and_then(Value::as_str).and_then(|n| n.parse().ok())
+}
+
+fn thread(info: &Value<'_>) -> Option<Thread> {
+    let id = number(info.get("id"))?;
+    let name = info
+        .get("name")
+        .or_else(|| info.get("target-id"))
+        .and_then(Value::as_str)
+        .map_or_else(|| format!("Thread {}", id), str::to_owned);
+    Some(Thread { id, name })
+}
+
+fn stack_frame(
+    client: &ClientCapabilities,
+    frames: &mut Frames,
+    thread: u32,
+    frame: &Value<'_>,
+) -> Option<StackFrame> {
+    let level = frame.get("level")?.as_str()?.parse().ok()?;
+    let source = frame
+        .get("fullname")
+        .and_then(Value::as_str)
+        .map(|path| Source {
+            name: frame.get("file").and_then(Value::as_str).map(str::to_owned),
+            path: Some(client.path(path)),
+            ..Default::default()
+        });
+    let name = frame
+        .get("func")
+        .or_else(|| frame.get("addr"))
+        .and_then(Value::as_str)
+        .unwrap_or("??")
+        .to_owned();
+    Some(StackFrame {
+        can_restart: None,
+        // GDB doesn't know columns, the first one does
+        column: client.column(1),
+        end_column: None,
+        end_line: None,
+        id: frames.id(thread, level),
+        instruction_pointer_reference: client
+            .memory_reference(frame.get("addr").and_then(Value::as_str)),
+        // Frames without a source are on line 0 whatever the base
+        line: number(frame.get("line")).map_or(0, |line| client.line(line)),
+        module_id: None,
+        name,
+        presentation_hint: None,
+        source,
+    })
+}
+
+// Turns GDB's records into events until it goes away
+async fn forward(
+    mut events: broadcast::Receiver<MiEvent>,
+    sender: DapSender,
+    frames: Arc<Mutex<Frames>>,
+) {
+    loop {
+        let record = match events.recv().await {
+            Ok(MiEvent::Record(record)) => record,
+            Ok(MiEvent::Exited(_)) | Err(broadcast::error::RecvError::Closed) => break,
+            Err(broadcast::error::RecvError::Lagged(_)) => continue,
+        };
+        let result = match record {
+            OOB::AsyncRecord(AsyncOutput::ExeAsync(data)) => {
+                if matches!(data.1, OutputClass::Running | OutputClass::Stopped) {
+                    frames.lock().unwrap().clear();
+                }
+                exec_event(&data, &sender)
+            }
+            OOB::AsyncRecord(AsyncOutput::NotifyAsync(data)) => thread_event(&data, &sender),
+            OOB::StreamRecord(StreamOutput::Console(text)) => {
+                emit(&sender, output("console", &text))
+            }
+            OOB::StreamRecord(StreamOutput::Target(text)) => emit(&sender, output("stdout", &text)),
+            _ => Ok(()),
+        };
+        if result.is_err() {
+            return;
+        }
+    }
+    let _ = sender.send(Event::new(TerminatedEventBody { restart: None }));
+}
+
+// Sends what the program prints as `stdout` output, until its terminal goes
+// away
+async fn forward_output(mut terminal: PtyReader, sender: DapSender) {
+    let mut buf = Vec::with_capacity(4096);
+    loop {
+        match terminal.read_buf(&mut buf).await {
+            Ok(0) | Err(_) => return,
+            Ok(_) => {}
+        }
+        // A character cut in two by the read waits for the rest of it
+        let complete = match std::str::from_utf8(&buf) {
+            Ok(_) => buf.len(),
+            Err(e) if e.error_len().is_none() => e.valid_up_to(),
+            Err(_) => buf.len(),
+        };
+        let text = String::from_utf8_lossy(&buf[..complete]).into_owned();
+        buf.drain(..complete);
+        if !text.is_empty() && emit(&sender, output("stdout", &text)).is_err() {
+            return;
+        }
+    }
+}
+
+fn emit<M: serde::Serialize>(sender: &DapSender, message: M) -> Result<(), CodecError> {
+    sender.send(message).map(drop)
+}
+
+fn output(category: &str, text: &str) -> Event<OutputEventBody> {
+    Event::new(OutputEventBody {
+        category: Some(category.to_owned()),
+        column: None,
+        data: None,
+        group: None,
+        line: None,
+        location_reference: None,
+        output: text.to_owned(),
+        source: None,
+        variables_reference: None,
+    })
+}
+
+fn exec_event(data: &OutputData<'_>, sender: &DapSender) -> Result<(), CodecError> {
+    let thread_id = number(data.get("thread-id"));
+    match data.1 {
+        OutputClass::Running => match thread_id {
+            // Continuing everything is implied by the request's response
+            None => Ok(()),
+            Some(thread_id) => emit(
+                sender,
+                Event::new(ContinuedEventBody {
+                    all_threads_continued: None,
+                    thread_id,
+                }),
+            ),
+        },
+        OutputClass::Stopped => {
+            let reason = data.get_str("reason").unwrap_or_default();
+            if reason.starts_with("exited") {
+                // Exit codes are printed in octal
+                let exit_code = data
+                    .get_str("exit-code")
+                    .and_then(|code| i64::from_str_radix(code, 8).ok())
+                    .unwrap_or(0);
+                emit(sender, Event::new(ExitedEventBody { exit_code }))?;
+                return emit(sender, Event::new(TerminatedEventBody { restart: None }));
+            }
+            let (reason, text) = match reason {
+                "breakpoint-hit" => ("breakpoint", None),
+                "end-stepping-range" | "function-finished" => ("step", None),
+                "signal-received" => ("exception", data.get_str("signal-name")),
+                _ => ("pause", None),
+            };
+            emit(
+                sender,
+                Event::new(StoppedEventBody {
+                    all_threads_stopped: Some(data.get_str("stopped-threads") == Some("all")),
+                    description: None,
+                    hit_breakpoint_ids: None,
+                    preserve_focus_hint: None,
+                    reason: reason.to_owned(),
+                    text: text.map(str::to_owned),
+                    thread_id,
+                }),
+            )
+        }
+        _ => Ok(()),
+    }
+}
+
+fn thread_event(data: &OutputData<'_>, sender: &DapSender) -> Result<(), CodecError> {
+    let reason = match data.1 {
+        OutputClass::ThreadCreated => "started",
+        OutputClass::ThreadExited => "exited",
+        _ => return Ok(()),
+    };
+    match number(data.get("id")) {
+        Some(thread_id) => emit(
+            sender,
+            Event::new(ThreadEventBody {
+                reason: reason.to_owned(),
+                thread_id,
+            }),
+        ),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::os::unix::process::ExitStatusExt;
+
+    use rust_mi::testing::FakeGdb;
+    use serde_json::json;
+    use tokio::io::{AsyncWriteExt, DuplexStream};
+
+    use super::*;
+
+    // The editor's side of a session
+    struct Client {
+        stream: DuplexStream,
+        codec: DapCodec,
+        buf: BytesMut,
+        seq: i64,
+    }
+
+    impl Client {
+        async fn write(&mut self, message: serde_json::Value) {
+            let body = message.to_string();
+            let framed = format!("Content-Length: {}\r\n\r\n{}", body.len(), body);
+            self.stream.write_all(framed.as_bytes()).await.unwrap();
+        }
+
+        async fn request(&mut self, command: &str, arguments: serde_json::Value) -> i64 {
+            self.seq += 1;
+            self.write(json!({
+                "seq": self.seq, "type": "request", "command": command, "arguments": arguments
+            }))
+            .await;
+            self.seq
+        }
+
+        // Answers a reverse request
+        async fn respond(&mut self, request: &serde_json::Value, body: serde_json::Value) {
+            self.seq += 1;
+            self.write(json!({
+                "seq": self.seq, "type": "response", "request_seq": request["seq"],
+                "success": true, "command": request["command"], "body": body
+            }))
+            .await;
+        }
+
+        async fn receive(&mut self) -> Option<serde_json::Value> {
+            loop {
+                match self.codec.decode(&mut self.buf).unwrap() {
+                    // Reverse requests, the adapter doesn't take any itself
+                    Some(DapMessage::Request(Incoming {
+                        seq,
+                        request: IncomingRequest::Unknown { command, arguments },
+                    })) => {
+                        return Some(json!({
+                            "seq": seq, "type": "request", "command": command,
+                            "arguments": arguments
+                        }))
+                    }
+                    Some(message) => return Some(serde_json::to_value(message).unwrap()),
+                    None => {}
+                }
+                if self.stream.read_buf(&mut self.buf).await.unwrap() == 0 {
+                    return None;
+                }
+            }
+        }
+
+        // Skips events until the response to `seq`
+        async fn response(&mut self, seq: i64) -> serde_json::Value {
+            loop {
+                let message = self.receive().await.unwrap();
+                if message["type"] == "response" && message["request_seq"] == seq {
+                    return message;
+                }
+            }
+        }
+
+        async fn event(&mut self, event: &str) -> serde_json::Value {
+            loop {
+                let message = self.receive().await.unwrap();
+                if message["type"] == "event" && message["event"] == event {
+                    return message;
+                }
+            }
+        }
+    }
+
+    fn start() -> (FakeGdb, Client, JoinHandle<io::Result<()>>) {
+        connect(true)
+    }
+
+    fn connect(local: bool) -> (FakeGdb, Client, JoinHandle<io::Result<()>>) {
+        let (fake, controller) = FakeGdb::controller().unwrap();
+        let (client, adapter) = tokio::io::duplex(4096);
+        let (reader, writer) = tokio::io::split(adapter);
+        let session = tokio::spawn(serve(reader, writer, controller, local));
+        let client = Client {
+            stream: client,
+            codec: DapCodec::new(),
+            buf: BytesMut::new(),
+            seq: 0,
+        };
+        (fake, client, session)
+    }
+
+    const RUN: [&str; 4] = [
+        "^running",
+        r#"*running,thread-id="all""#,
+        r#"=thread-created,id="1",group-id="i1""#,
+        r#"*stopped,reason="breakpoint-hit",bkptno="1",thread-id="1",stopped-threads="all""#,
+    ];
+    const THREADS: &str =
+        r#"^done,threads=[{id="1",target-id="process 42",name="app",state="stopped"}]"#;
+    const FRAMES: &str = r#"^done,stack=[frame={level="0",addr="0x401136",func="main",file="app.c",fullname="/src/app.c",line="7"},frame={level="1",addr="0x7f00",func="__libc_start_main"}]"#;
+
+    #[tokio::test]
+    async fn test_launch_session() {
+        let (fake, mut client, session) = start();
+        fake.on("-file-exec-and-symbols", ["^done"])
+            .on("-inferior-tty-set", ["^done"])
+            .on("-exec-run", RUN)
+            .on("-thread-info", [THREADS])
+            .on("-stack-list-frames", [FRAMES]);
+
+        let seq = client
+            .request("initialize", json!({"adapterID": "gdb"}))
+            .await;
+        let response = client.response(seq).await;
+        assert_eq!(response["success"], true);
+        assert_eq!(response["body"]["supportsConfigurationDoneRequest"], true);
+        client.event("initialized").await;
+
+        let seq = client.request("launch", json!({"program": "./app"})).await;
+        assert_eq!(client.response(seq).await["success"], true);
+        let seq = client.request("configurationDone", json!(null)).await;
+        assert_eq!(client.response(seq).await["success"], true);
+        let stopped = client.event("stopped").await;
+        assert_eq!(stopped["body"]["reason"], "breakpoint");
+        assert_eq!(stopped["body"]["threadId"], 1);
+
+        let seq = client.request("threads", json!(null)).await;
+        assert_eq!(
+            client.response(seq).await["body"]["threads"],
+            json!([{"id": 1, "name": "app"}])
+        );
+        let seq = client
+            .request("stackTrace", json!({"threadId": 1, "levels": 1}))
+            .await;
+        let body = client.response(seq).await["body"].clone();
+        assert_eq!(body["totalFrames"], 2);
+        assert_eq!(body["stackFrames"][0]["name"], "main");
+        assert_eq!(body["stackFrames"][0]["line"], 7);
+        assert_eq!(body["stackFrames"][0]["source"]["path"], "/src/app.c");
+
+        let seq = client.request("disconnect", json!({})).await;
+        assert_eq!(client.response(seq).await["success"], true);
+        session.await.unwrap().unwrap();
+        fake.assert_received_command("-gdb-exit");
+    }
+
+    #[tokio::test]
+    async fn test_errors() {
+        let (_fake, mut client, session) = start();
+        let seq = client.request("fancyNewThing", json!({})).await;
+        let response = client.response(seq).await;
+        assert_eq!(response["success"], false);
+        assert_eq!(response["command"], "fancyNewThing");
+        assert_eq!(
+            response["body"]["error"]["format"],
+            "fancyNewThing isn't supported"
+        );
+        let seq = client.request("next", json!({"nope": 1})).await;
+        let response = client.response(seq).await;
+        assert_eq!(response["success"], false);
+        assert_eq!(response["command"], "next");
+        let error = response["body"]["error"]["format"].as_str().unwrap();
+        assert!(error.starts_with("invalid arguments: "), "{}", error);
+
+        // Hanging up ends the session too
+        drop(client);
+        session.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_cancel() {
+        let (fake, mut client, session) = start();
+        // GDB never answers
+        fake.on("-thread-info", Vec::<String>::new());
+        let seq = client
+            .request("initialize", json!({"adapterID": "gdb"}))
+            .await;
+        assert_eq!(
+            client.response(seq).await["body"]["supportsCancelRequest"],
+            true
+        );
+
+        let running = client.request("threads", json!({})).await;
+        let queued = client.request("threads", json!({})).await;
+        for request_id in [queued, running] {
+            let seq = client
+                .request("cancel", json!({"requestId": request_id}))
+                .await;
+            assert_eq!(client.response(seq).await["success"], true);
+        }
+        for seq in [running, queued] {
+            let response = client.response(seq).await;
+            assert_eq!(response["success"], false);
+            assert_eq!(response["message"], "cancelled");
+        }
+        // The queued one never reached GDB
+        fake.assert_received(&["-thread-info"]);
+
+        // Answered requests can't be cancelled anymore
+        let seq = client
+            .request("cancel", json!({"requestId": running}))
+            .await;
+        assert_eq!(client.response(seq).await["success"], true);
+
+        drop(client);
+        session.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_client_capabilities() {
+        let (fake, mut client, session) = start();
+        fake.on("-file-exec-and-symbols", ["^done"])
+            .on("-inferior-tty-set", ["^done"])
+            .on("-stack-list-frames", [FRAMES]);
+
+        let seq = client
+            .request(
+                "initialize",
+                json!({
+                    "adapterID": "gdb", "linesStartAt1": false, "columnsStartAt1": false,
+                    "pathFormat": "uri", "supportsProgressReporting": true
+                }),
+            )
+            .await;
+        client.response(seq).await;
+
+        let seq = client
+            .request("launch", json!({"program": "file:///src/my%20app"}))
+            .await;
+        let start = client.event("progressStart").await;
+        assert_eq!(start["body"]["title"], "Loading /src/my app");
+        let end = client.event("progressEnd").await;
+        assert_eq!(end["body"]["progressId"], start["body"]["progressId"]);
+        assert_eq!(client.response(seq).await["success"], true);
+        fake.assert_received_command("-file-exec-and-symbols \"/src/my app\"");
+
+        let seq = client.request("stackTrace", json!({"threadId": 1})).await;
+        let frame = client.response(seq).await["body"]["stackFrames"][0].clone();
+        assert_eq!(frame["line"], 6);
+        assert_eq!(frame["column"], 0);
+        assert_eq!(frame["source"]["path"], "file:///src/app.c");
+        // Addresses are only passed on to clients that take memory references
+        assert_eq!(frame.get("instructionPointerReference"), None);
+
+        drop(client);
+        session.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_variables() {
+        let (fake, mut client, session) = start();
+        fake.on("-stack-list-frames", [FRAMES]).on(
+            "-stack-list-variables",
+            [r#"^done,variables=[{name="argc",arg="1",type="int",value="2"},{name="config",type="struct config"}]"#],
+        );
+        let seq = client
+            .request(
+                "initialize",
+                json!({"adapterID": "gdb", "supportsVariableType": true}),
+            )
+            .await;
+        client.response(seq).await;
+
+        let seq = client.request("stackTrace", json!({"threadId": 2})).await;
+        let frames = client.response(seq).await["body"]["stackFrames"].clone();
+        // Frame 1 of thread 2
+        let frame_id = frames[1]["id"].clone();
+        let seq = client.request("scopes", json!({"frameId": frame_id})).await;
+        let scopes = client.response(seq).await["body"]["scopes"].clone();
+        assert_eq!(scopes[0]["name"], "Locals");
+        let reference = scopes[0]["variablesReference"].clone();
+        assert_eq!(reference, frame_id);
+
+        let seq = client
+            .request("variables", json!({"variablesReference": reference}))
+            .await;
+        let variables = client.response(seq).await["body"]["variables"].clone();
+        fake.assert_received(&[
+            "-stack-list-frames --thread 2",
+            "-stack-list-variables --thread 2 --frame 1 --simple-values",
+        ]);
+        assert_eq!(variables[0]["name"], "argc");
+        assert_eq!(variables[0]["value"], "2");
+        assert_eq!(variables[0]["type"], "int");
+        assert_eq!(variables[1]["value"], "{...}");
+        assert_eq!(variables[1]["type"], "struct config");
+
+        let seq = client.request("scopes", json!({"frameId": 99})).await;
+        assert_eq!(client.response(seq).await["success"], false);
+
+        drop(client);
+        session.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_program_output() {
+        let (fake, mut client, session) = start();
+        fake.on("-file-exec-and-symbols", ["^done"])
+            .on("-inferior-tty-set", ["^done"]);
+        let seq = client
+            .request("initialize", json!({"adapterID": "gdb"}))
+            .await;
+        client.response(seq).await;
+        let seq = client.request("launch", json!({"program": "./app"})).await;
+        assert_eq!(client.response(seq).await["success"], true);
+
+        // The program gets a terminal of its own rather than GDB's
+        let received = fake.received();
+        let tty = received[1].strip_prefix("-inferior-tty-set ").unwrap();
+        let mut program = std::fs::OpenOptions::new().write(true).open(tty).unwrap();
+        std::io::Write::write_all(&mut program, "héllo\n".as_bytes()).unwrap();
+        let output = client.event("output").await;
+        assert_eq!(output["body"]["category"], "stdout");
+        assert_eq!(output["body"]["output"], "héllo\n");
+
+        drop(client);
+        session.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_non_stop() {
+        let (fake, mut client, session) = start();
+        fake.on("-gdb-set", ["^done"])
+            .on("-file-exec-and-symbols", ["^done"])
+            .on("-inferior-tty-set", ["^done"])
+            .on("-exec-continue", ["^running"])
+            .on("-exec-next", ["^running"])
+            .on(
+                "-thread-info",
+                [
+                    r#"=thread-created,id="1",group-id="i1""#,
+                    r#"=thread-created,id="2",group-id="i1""#,
+                    r#"*stopped,thread-id="1",stopped-threads="all""#,
+                    r#"^done,threads=[{id="1",target-id="Thread 42.1",state="stopped"},{id="2",target-id="Thread 42.2",state="stopped"}]"#,
+                ],
+            );
+        let seq = client
+            .request("launch", json!({"program": "./app", "nonStop": true}))
+            .await;
+        assert_eq!(client.response(seq).await["success"], true);
+        let seq = client.request("threads", json!({})).await;
+        client.response(seq).await;
+
+        let seq = client.request("next", json!({"threadId": 1})).await;
+        assert_eq!(client.response(seq).await["success"], true);
+        let seq = client
+            .request("next", json!({"threadId": 1, "singleThread": true}))
+            .await;
+        assert_eq!(client.response(seq).await["success"], true);
+        let seq = client
+            .request("continue", json!({"threadId": 1, "singleThread": true}))
+            .await;
+        let response = client.response(seq).await;
+        assert_eq!(response["body"]["allThreadsContinued"], false);
+        let seq = client.request("continue", json!({"threadId": 1})).await;
+        let response = client.response(seq).await;
+        assert_eq!(response["body"]["allThreadsContinued"], true);
+        let mut received = fake.received();
+        // On a pty of its own
+        assert!(received.remove(3).starts_with("-inferior-tty-set /dev/"));
+        assert_eq!(
+            received,
+            [
+                "-gdb-set mi-async on",
+                "-gdb-set non-stop on",
+                "-file-exec-and-symbols ./app",
+                "-thread-info",
+                // The other thread goes on, before the step can be over
+                "-exec-continue --thread 2",
+                "-exec-next --thread 1",
+                "-exec-next --thread 1",
+                "-exec-continue --thread 1",
+                "-exec-continue --all",
+            ]
+        );
+
+        drop(client);
+        session.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_pause_while_running() {
+        let (fake, mut client, session) = start();
+        // Running before the answer, so `next` is sure to find it so
+        fake.on(
+            "-exec-continue",
+            [r#"*running,thread-id="all""#, "^running"],
+        )
+        .on(
+            "-exec-interrupt",
+            [
+                "^done",
+                r#"*stopped,reason="signal-received",signal-name="SIGINT",thread-id="1",stopped-threads="all""#,
+            ],
+        );
+        // Stopped at first, as far as the controller knows
+        fake.on(
+            "-thread-info",
+            [
+                r#"=thread-created,id="1",group-id="i1""#,
+                r#"*stopped,thread-id="1",stopped-threads="all""#,
+                THREADS,
+            ],
+        );
+        let seq = client.request("threads", json!({})).await;
+        client.response(seq).await;
+        let seq = client.request("continue", json!({"threadId": 1})).await;
+        assert_eq!(client.response(seq).await["success"], true);
+
+        // Turned down rather than held back until the program stops
+        let seq = client.request("next", json!({"threadId": 1})).await;
+        let response = client.response(seq).await;
+        assert_eq!(response["success"], false);
+        let seq = client.request("pause", json!({"threadId": 1})).await;
+        assert_eq!(client.response(seq).await["success"], true);
+        fake.assert_received(&["-thread-info", "-exec-continue", "-exec-interrupt"]);
+
+        drop(client);
+        session.await.unwrap().unwrap();
+        // The step didn't fire at the stop either
+        fake.assert_received(&[
+            "-thread-info",
+            "-exec-continue",
+            "-exec-interrupt",
+            "-gdb-exit",
+        ]);
+    }
+
+    #[test]
+    fn test_frame_ids() {
+        let mut frames = Frames::default();
+        // Deep recursion doesn't run into the next thread's frames
+        let deep = frames.id(1, 70000);
+        let other = frames.id(2, 4464);
+        assert_ne!(deep, other);
+        assert_eq!(frames.id(1, 70000), deep);
+        assert_eq!(frames.get(deep), Some((1, 70000)));
+        assert_eq!(frames.get(0), None);
+        frames.clear();
+        assert_eq!(frames.get(deep), None);
+    }
+
+    #[tokio::test]
+    async fn test_integrated_terminal() {
+        let (fake, mut client, session) = start();
+        fake.on("-file-exec-and-symbols", ["^done"])
+            .on("-gdb-set", ["^done"])
+            .on("-inferior-tty-set", ["^done"]);
+        let seq = client
+            .request(
+                "initialize",
+                json!({"adapterID": "gdb", "supportsRunInTerminalRequest": true}),
+            )
+            .await;
+        client.response(seq).await;
+
+        let seq = client
+            .request(
+                "launch",
+                json!({"program": "./app", "cwd": "/srv", "console": "integratedTerminal"}),
+            )
+            .await;
+        let request = loop {
+            let message = client.receive().await.unwrap();
+            if message["type"] == "request" {
+                break message;
+            }
+        };
+        assert_eq!(request["command"], "runInTerminal");
+        assert_eq!(request["arguments"]["kind"], "integrated");
+        assert_eq!(request["arguments"]["cwd"], "/srv");
+        // Stands in for the shell, which writes its tty to the last argument
+        let args = request["arguments"]["args"].as_array().unwrap();
+        let file = args.last().unwrap().as_str().unwrap();
+        std::fs::write(file, "/dev/pts/7\n").unwrap();
+        let mut shell = std::process::Command::new("sleep")
+            .arg("60")
+            .spawn()
+            .unwrap();
+        client
+            .respond(&request, json!({"shellProcessId": shell.id()}))
+            .await;
+
+        assert_eq!(client.response(seq).await["success"], true);
+        fake.assert_received(&[
+            "-file-exec-and-symbols ./app",
+            "-gdb-set cwd /srv",
+            "-inferior-tty-set /dev/pts/7",
+        ]);
+        // Cleaned up once read
+        assert!(!std::path::Path::new(file).exists());
+
+        drop(client);
+        session.await.unwrap().unwrap();
+        // The terminal is closed with the session
+        assert_eq!(shell.wait().unwrap().signal(), Some(libc::SIGHUP));
+    }
+
+    #[tokio::test]
+    async fn test_remote_terminal() {
+        let (fake, mut client, session) = connect(false);
+        let seq = client
+            .request(
+                "initialize",
+                json!({"adapterID": "gdb", "supportsRunInTerminalRequest": true}),
+            )
+            .await;
+        client.response(seq).await;
+
+        // The tty file would be on the wrong machine
+        let seq = client
+            .request(
+                "launch",
+                json!({"program": "./app", "console": "integratedTerminal"}),
+            )
+            .await;
+        let response = client.response(seq).await;
+        assert_eq!(response["success"], false);
+        assert!(response["body"]["error"]["format"]
+            .as_str()
+            .unwrap()
+            .contains("internalConsole"));
+        assert!(fake.received().is_empty());
+
+        drop(client);
+        session.await.unwrap().unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_gdb_hangs_on_exit() {
+        let (fake, mut client, session) = start();
+        fake.on("-gdb-exit", Vec::<String>::new());
+        let seq = client.request("disconnect", json!({})).await;
+        assert_eq!(client.response(seq).await["success"], true);
+        // Still ends, once GDB had its chance
+        session.await.unwrap().unwrap();
+        fake.assert_received_command("-gdb-exit");
+    }
+}
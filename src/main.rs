@@ -1,6 +1,105 @@
 mod dap;
-use dap::types::*;
+mod server;
+mod sessions;
 
-fn main() {
-    println!("Hello, world!");
+use std::{env, path::PathBuf, process};
+
+const USAGE: &str = "usage: simpledap [--port <port> | --socket <path>]
+
+Talks DAP on stdin and stdout, or serves a session per connection on
+127.0.0.1:<port> with --port or on a Unix socket only the current user
+can connect to with --socket.";
+
+/// Where the client connects.
+#[derive(Debug, Default, PartialEq)]
+enum Listen {
+    #[default]
+    Stdio,
+    Tcp(u16),
+    Unix(PathBuf),
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct Options {
+    listen: Listen,
+}
+
+impl Options {
+    fn parse<I: Iterator<Item = String>>(mut args: I) -> Result<Options, String> {
+        let mut options = Options::default();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--port" => {
+                    let port = args.next().ok_or("--port needs a port number")?;
+                    let port = port
+                        .parse()
+                        .map_err(|_| format!("invalid port {:?}", port))?;
+                    options.listen(Listen::Tcp(port))?;
+                }
+                "--socket" => {
+                    let path = args.next().ok_or("--socket needs a path")?;
+                    options.listen(Listen::Unix(path.into()))?;
+                }
+                "-h" | "--help" => {
+                    println!("{}", USAGE);
+                    process::exit(0);
+                }
+                arg => return Err(format!("unexpected argument {:?}", arg)),
+            }
+        }
+        Ok(options)
+    }
+
+    fn listen(&mut self, listen: Listen) -> Result<(), String> {
+        if self.listen != Listen::Stdio {
+            return Err("--port and --socket can't be combined".to_owned());
+        }
+        self.listen = listen;
+        Ok(())
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let options = Options::parse(env::args().skip(1)).unwrap_or_else(|e| {
+        eprintln!("simpledap: {}\n\n{}", e, USAGE);
+        process::exit(2);
+    });
+    let result = match &options.listen {
+        Listen::Stdio => server::stdio().await,
+        Listen::Tcp(port) => server::tcp(*port).await,
+        Listen::Unix(path) => server::unix(path).await,
+    };
+    if let Err(e) = result {
+        eprintln!("simpledap: {}", e);
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Options, String> {
+        Options::parse(args.iter().map(|a| a.to_string()))
+    }
+
+    #[test]
+    fn test_options() {
+        let listen = |options: Options| options.listen;
+        assert_eq!(parse(&[]).map(listen), Ok(Listen::Stdio));
+        assert_eq!(
+            parse(&["--port", "4711"]).map(listen),
+            Ok(Listen::Tcp(4711))
+        );
+        assert_eq!(
+            parse(&["--socket", "/tmp/dap.sock"]).map(listen),
+            Ok(Listen::Unix("/tmp/dap.sock".into()))
+        );
+        assert!(parse(&["--port"]).is_err());
+        assert!(parse(&["--port", "http"]).is_err());
+        assert!(parse(&["--socket"]).is_err());
+        assert!(parse(&["--port", "4711", "--socket", "/tmp/dap.sock"]).is_err());
+        assert!(parse(&["--verbose"]).is_err());
+    }
 }
@@ -0,0 +1,287 @@
+use std::{fs::{self, DirBuilder},
+          future::Future,
+          io,
+          os::unix::{fs::{DirBuilderExt, FileTypeExt, PermissionsExt},
+                     net::UnixStream},
+          path::{Path, PathBuf},
+          process,
+          time::Duration};
+
+use rust_mi::MIController;
+use tokio::{io::{AsyncRead, AsyncWrite},
+            net::{TcpListener, UnixListener},
+            signal::unix::{signal, SignalKind}};
+
+use crate::{dap::session, sessions::Sessions};
+
+// How long to stop accepting when connections can't be taken, i.e. when out
+// of file descriptors
+const ACCEPT_BACKOFF: Duration = Duration::from_millis(100);
+
+async fn gdb() -> io::Result<MIController> {
+    MIController::new()
+        .await
+        .map_err(|e| io::Error::other(e.to_string()))
+}
+
+/// Serves a single session on stdin and stdout.
+pub async fn stdio() -> io::Result<()> {
+    let controller = gdb().await?;
+    session::serve(tokio::io::stdin(), tokio::io::stdout(), controller, true).await
+}
+
+/// Runs one connection's session, with a GDB of its own.
+fn spawn_session<R, W>(sessions: &Sessions, peer: String, local: bool, reader: R, writer: W)
+where
+    R: AsyncRead + Unpin + Send + 'static,
+    W: AsyncWrite + Unpin + Send + 'static,
+{
+    sessions.spawn(peer, async move {
+        let controller = gdb().await?;
+        session::serve(reader, writer, controller, local).await
+    });
+}
+
+// Accept errors concern one connection or a passing shortage, neither is a
+// reason to drop the sessions already running
+async fn accepted<T>(accepted: io::Result<T>) -> Option<T> {
+    let e = match accepted {
+        Ok(connection) => return Some(connection),
+        Err(e) => e,
+    };
+    eprintln!("simpledap: accepting a connection: {}", e);
+    let transient = matches!(
+        e.kind(),
+        io::ErrorKind::ConnectionAborted
+            | io::ErrorKind::ConnectionReset
+            | io::ErrorKind::Interrupted
+    );
+    if !transient {
+        // Give sessions a chance to close some descriptors
+        tokio::time::sleep(ACCEPT_BACKOFF).await;
+    }
+    None
+}
+
+/// Serves a session per connection on `port`, until asked to stop.
+pub async fn tcp(port: u16) -> io::Result<()> {
+    // Only local editors are expected to connect
+    let listener = TcpListener::bind(("127.0.0.1", port)).await?;
+    eprintln!("simpledap: listening on {}", listener.local_addr()?);
+    let sessions = Sessions::new();
+    listen(
+        terminated(),
+        || listener.accept(),
+        |(stream, peer)| {
+            let (reader, writer) = stream.into_split();
+            // Could come through a forwarded port from anywhere
+            spawn_session(&sessions, peer.to_string(), false, reader, writer);
+        },
+    )
+    .await
+}
+
+/// A listening Unix socket, its file goes away with it.
+#[derive(Debug)]
+struct UnixSocket {
+    listener: UnixListener,
+    path: PathBuf,
+}
+
+impl Drop for UnixSocket {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Binds `path` so that only the current user can connect.
+///
+/// A socket left behind by an earlier run is replaced, one a server still
+/// listens on or anything else at `path` is an error.
+fn bind_unix(path: &Path) -> io::Result<UnixSocket> {
+    match fs::symlink_metadata(path) {
+        Ok(metadata) if metadata.file_type().is_socket() => {
+            if UnixStream::connect(path).is_ok() {
+                return Err(io::Error::new(
+                    io::ErrorKind::AddrInUse,
+                    format!("{} is in use by another server", path.display()),
+                ));
+            }
+            fs::remove_file(path)?
+        }
+        Ok(_) => {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} exists and isn't a socket", path.display()),
+            ))
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e),
+    }
+    // Bound in a directory nobody else can enter and moved into place once
+    // its permissions are tightened, the umask could leave it open before
+    let parent = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    let private = parent.join(format!(".simpledap-{}", process::id()));
+    // Left behind by an earlier run that had the same pid and was killed
+    match fs::remove_dir_all(&private) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+        _ => {}
+    }
+    DirBuilder::new().mode(0o700).create(&private)?;
+    let staging = private.join("socket");
+    let bound = UnixListener::bind(&staging).and_then(|listener| {
+        fs::set_permissions(&staging, fs::Permissions::from_mode(0o600))?;
+        fs::rename(&staging, path)?;
+        Ok(listener)
+    });
+    let _ = fs::remove_file(&staging);
+    fs::remove_dir(&private)?;
+    Ok(UnixSocket {
+        listener: bound?,
+        path: path.to_owned(),
+    })
+}
+
+// Resolves when the server is asked to stop with ^C or SIGTERM
+async fn terminated() -> io::Result<()> {
+    let mut terminate = signal(SignalKind::terminate())?;
+    tokio::select! {
+        interrupted = tokio::signal::ctrl_c() => interrupted,
+        _ = terminate.recv() => Ok(()),
+    }
+}
+
+// Hands what `accept` takes to `serve` until `stop` resolves, i.e. with
+// `terminated`
+async fn listen<A, F, T, S>(stop: S, mut accept: A, mut serve: impl FnMut(T)) -> io::Result<()>
+where
+    A: FnMut() -> F,
+    F: Future<Output = io::Result<T>>,
+    S: Future<Output = io::Result<()>>,
+{
+    tokio::pin!(stop);
+    loop {
+        let accept = tokio::select! {
+            accept = accept() => accept,
+            stopped = &mut stop => {
+                eprintln!("simpledap: shutting down");
+                return stopped;
+            }
+        };
+        if let Some(connection) = accepted(accept).await {
+            serve(connection);
+        }
+    }
+}
+
+/// Serves a session per connection on the Unix socket at `path`, until
+/// asked to stop. The socket is removed on the way out.
+pub async fn unix(path: &Path) -> io::Result<()> {
+    let socket = bind_unix(path)?;
+    eprintln!("simpledap: listening on {}", path.display());
+    let sessions = Sessions::new();
+    listen(
+        terminated(),
+        || socket.listener.accept(),
+        // Clients on a socket are unnamed, the session id tells them apart
+        |(stream, _)| {
+            let (reader, writer) = stream.into_split();
+            spawn_session(&sessions, path.display().to_string(), true, reader, writer);
+        },
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    fn socket_path(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("simpledap-{}-{}.sock", process::id(), name))
+    }
+
+    #[tokio::test]
+    async fn test_bind_unix() {
+        let path = socket_path("bind");
+        let socket = bind_unix(&path).unwrap();
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        assert!(!env::temp_dir()
+            .join(format!(".simpledap-{}", process::id()))
+            .exists());
+        tokio::net::UnixStream::connect(&path).await.unwrap();
+        socket.listener.accept().await.unwrap();
+
+        // Cleaned up with the listener
+        drop(socket);
+        assert!(!path.exists());
+
+        // A stale socket is replaced, a live one isn't
+        let live = std::os::unix::net::UnixListener::bind(&path).unwrap();
+        assert_eq!(
+            bind_unix(&path).unwrap_err().kind(),
+            io::ErrorKind::AddrInUse
+        );
+        drop(live);
+        drop(bind_unix(&path).unwrap());
+
+        // So is a staging directory a killed run left behind
+        let private = env::temp_dir().join(format!(".simpledap-{}", process::id()));
+        fs::create_dir(&private).unwrap();
+        fs::write(private.join("socket"), "").unwrap();
+        drop(bind_unix(&path).unwrap());
+        assert!(!private.exists());
+
+        // Other files are left alone
+        fs::write(&path, "not a socket").unwrap();
+        assert_eq!(
+            bind_unix(&path).unwrap_err().kind(),
+            io::ErrorKind::AlreadyExists
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "not a socket");
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_listen() {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let (stop, stopped) = tokio::sync::oneshot::channel::<()>();
+        let (served_tx, mut served) = tokio::sync::mpsc::unbounded_channel();
+        let server = tokio::spawn(async move {
+            listen(
+                async { stopped.await.map_err(io::Error::other) },
+                || listener.accept(),
+                |(_, peer)| served_tx.send(peer).unwrap(),
+            )
+            .await
+        });
+
+        let client = tokio::net::TcpStream::connect(address).await.unwrap();
+        assert_eq!(served.recv().await, Some(client.local_addr().unwrap()));
+        stop.send(()).unwrap();
+        server.await.unwrap().unwrap();
+        // Not listening anymore
+        assert!(tokio::net::TcpStream::connect(address).await.is_err());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_accepted() {
+        assert_eq!(accepted(Ok(1)).await, Some(1));
+        let aborted = io::Error::from(io::ErrorKind::ConnectionAborted);
+        assert_eq!(accepted::<()>(Err(aborted)).await, None);
+
+        // Out of file descriptors
+        let start = tokio::time::Instant::now();
+        assert_eq!(
+            accepted::<()>(Err(io::Error::from_raw_os_error(24))).await,
+            None
+        );
+        assert_eq!(start.elapsed(), ACCEPT_BACKOFF);
+    }
+}
@@ -0,0 +1,138 @@
+use std::{any::Any,
+          collections::BTreeMap,
+          future::Future,
+          io,
+          sync::{Arc, Mutex}};
+
+use tokio::task::JoinHandle;
+
+/// Keeps track of the sessions a server is running.
+///
+/// Every session runs in its own task with its own GDB, so a session that
+/// fails or panics only takes its own GDB down. Sessions come and go on
+/// their own, each change is logged along with what's still running.
+#[derive(Clone, Debug, Default)]
+pub struct Sessions {
+    active: Arc<Mutex<Active>>,
+}
+
+#[derive(Debug, Default)]
+struct Active {
+    next_id: u64,
+    peers: BTreeMap<u64, String>,
+}
+
+impl Sessions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs `session` for `peer` in the background.
+    ///
+    /// The returned handle finishes once the session is gone, however it
+    /// ended.
+    pub fn spawn<F>(&self, peer: String, session: F) -> JoinHandle<()>
+    where
+        F: Future<Output = io::Result<()>> + Send + 'static,
+    {
+        let id = {
+            let mut active = self.active.lock().unwrap();
+            active.next_id += 1;
+            let id = active.next_id;
+            active.peers.insert(id, peer.clone());
+            id
+        };
+        eprintln!("simpledap: session {} ({}) connected", id, peer);
+        self.log_active();
+
+        let task = tokio::spawn(session);
+        let sessions = self.clone();
+        tokio::spawn(async move {
+            match task.await {
+                Ok(Ok(())) => eprintln!("simpledap: session {} ({}) disconnected", id, peer),
+                Ok(Err(e)) => eprintln!("simpledap: session {} ({}) failed: {}", id, peer, e),
+                Err(e) if e.is_panic() => eprintln!(
+                    "simpledap: session {} ({}) crashed: {}",
+                    id,
+                    peer,
+                    panic_message(e.into_panic())
+                ),
+                Err(e) => eprintln!("simpledap: session {} ({}) stopped: {}", id, peer, e),
+            }
+            sessions.active.lock().unwrap().peers.remove(&id);
+            sessions.log_active();
+        })
+    }
+
+    /// The ids and peers of the sessions still running.
+    pub fn list(&self) -> Vec<(u64, String)> {
+        let active = self.active.lock().unwrap();
+        active
+            .peers
+            .iter()
+            .map(|(id, peer)| (*id, peer.clone()))
+            .collect()
+    }
+
+    fn log_active(&self) {
+        let active = self.list();
+        if active.is_empty() {
+            eprintln!("simpledap: no sessions active");
+            return;
+        }
+        let names: Vec<_> = active
+            .iter()
+            .map(|(id, peer)| format!("{} ({})", id, peer))
+            .collect();
+        eprintln!(
+            "simpledap: {} session(s) active: {}",
+            active.len(),
+            names.join(", ")
+        );
+    }
+}
+
+fn panic_message(panic: Box<dyn Any + Send>) -> String {
+    match panic.downcast::<String>() {
+        Ok(message) => *message,
+        Err(panic) => match panic.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "unknown panic".to_owned(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::oneshot;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_isolated_sessions() {
+        let sessions = Sessions::new();
+        let (finish, finished) = oneshot::channel::<()>();
+        let running = sessions.spawn("editor".to_owned(), async move {
+            finished.await.map_err(io::Error::other)
+        });
+        let crashed = sessions.spawn("crash".to_owned(), async { panic!("boom") });
+        let failed = sessions.spawn("fail".to_owned(), async {
+            Err(io::Error::other("gdb went away"))
+        });
+        crashed.await.unwrap();
+        failed.await.unwrap();
+
+        // The others ending doesn't touch the running session
+        assert_eq!(sessions.list(), vec![(1, "editor".to_owned())]);
+        finish.send(()).unwrap();
+        running.await.unwrap();
+        assert!(sessions.list().is_empty());
+    }
+
+    #[test]
+    fn test_panic_message() {
+        assert_eq!(panic_message(Box::new("boom")), "boom");
+        assert_eq!(panic_message(Box::new(format!("{}", 42))), "42");
+        assert_eq!(panic_message(Box::new(42)), "unknown panic");
+    }
+}